@@ -1,10 +1,10 @@
-//! Access to the `ToJsValue` trait for converting types into
-//! `wasm_bindgen::JsValue`s.
+//! Access to the `ToJsValue` and `FromJsValue` traits for converting types
+//! into, and back from, `wasm_bindgen::JsValue`s.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use js_sys::Array;
-use wasm_bindgen::{prelude::Closure, JsValue};
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
 
 use crate::imports::ObjectExt;
 
@@ -103,7 +103,7 @@ impl ToJsValue for f32 {
 
 impl ToJsValue for f64 {
     fn to_js_value(&self) -> JsValue {
-        JsValue::from_f64(*self as f64)
+        JsValue::from_f64(*self)
     }
 }
 
@@ -169,3 +169,163 @@ impl ToJsValue for () {
         JsValue::undefined()
     }
 }
+
+/// This trait is used to provide an implementation for converting a
+/// `wasm_bindgen::JsValue` back into a given type.
+pub trait FromJsValue: Sized {
+    /// Convert the given `wasm_bindgen::JsValue` to the current type.
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError>;
+}
+
+/// The error returned when a `wasm_bindgen::JsValue` could not be converted
+/// with [`FromJsValue`].
+#[derive(Debug)]
+pub struct FromJsValueError {
+    message: String,
+}
+
+impl FromJsValueError {
+    /// Create an error describing why the conversion failed.
+    pub fn new<T: Into<String>>(message: T) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Create an error for a value which was not of the expected type.
+    pub fn invalid_type(expected: &str, value: &JsValue) -> Self {
+        Self::new(format!("expected {expected}, found {value:?}"))
+    }
+}
+
+impl fmt::Display for FromJsValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for FromJsValueError {}
+
+impl FromJsValue for String {
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        value
+            .as_string()
+            .ok_or_else(|| FromJsValueError::invalid_type("a string", value))
+    }
+}
+
+impl FromJsValue for bool {
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        value
+            .as_bool()
+            .ok_or_else(|| FromJsValueError::invalid_type("a boolean", value))
+    }
+}
+
+macro_rules! from_js_value_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl FromJsValue for $ty {
+                fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+                    let v = value
+                        .as_f64()
+                        .ok_or_else(|| FromJsValueError::invalid_type("a number", value))?;
+
+                    // This also rejects `NaN` and the infinities.
+                    if v.fract() != 0.0 {
+                        return Err(FromJsValueError::invalid_type("an integer", value));
+                    }
+
+                    // Every integer type fits in an `i128`, and a larger float
+                    // saturates, so the conversion fails for it too.
+                    <$ty>::try_from(v as i128).map_err(|_| {
+                        FromJsValueError::new(format!(
+                            "{v} is out of range for `{}`",
+                            stringify!($ty)
+                        ))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+from_js_value_integer!(usize, isize, u64, i64, u32, i32, u16, i16, u8, i8);
+
+impl FromJsValue for f32 {
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        f64::from_js_value(value).map(|v| v as f32)
+    }
+}
+
+impl FromJsValue for f64 {
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        value
+            .as_f64()
+            .ok_or_else(|| FromJsValueError::invalid_type("a number", value))
+    }
+}
+
+impl<T> FromJsValue for Option<T>
+where
+    T: FromJsValue,
+{
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        if value.is_null() || value.is_undefined() {
+            Ok(None)
+        } else {
+            T::from_js_value(value).map(Some)
+        }
+    }
+}
+
+impl<T> FromJsValue for Vec<T>
+where
+    T: FromJsValue,
+{
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        if !Array::is_array(value) {
+            return Err(FromJsValueError::invalid_type("an array", value));
+        }
+
+        value
+            .unchecked_ref::<Array>()
+            .iter()
+            .map(|v| T::from_js_value(&v))
+            .collect()
+    }
+}
+
+impl<V> FromJsValue for HashMap<String, V>
+where
+    V: FromJsValue,
+{
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        if !value.is_object() || Array::is_array(value) {
+            return Err(FromJsValueError::invalid_type("an object", value));
+        }
+
+        value
+            .unchecked_ref::<ObjectExt>()
+            .entries()
+            .into_iter()
+            .map(|(k, v)| Ok((k, V::from_js_value(&v)?)))
+            .collect()
+    }
+}
+
+impl FromJsValue for JsValue {
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        Ok(value.to_owned())
+    }
+}
+
+impl FromJsValue for () {
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        if value.is_undefined() {
+            Ok(())
+        } else {
+            Err(FromJsValueError::invalid_type("undefined", value))
+        }
+    }
+}
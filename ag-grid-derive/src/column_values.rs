@@ -0,0 +1,91 @@
+use darling::{ast, FromDeriveInput, FromField};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens, TokenStreamExt};
+use syn::{DeriveInput, Generics};
+
+use crate::to_js_value;
+
+const UNSUPPORTED_ERROR: &str = r#"ColumnValues can only be derived for structs with named fields"#;
+
+pub(crate) fn column_values_impl(input: DeriveInput) -> proc_macro::TokenStream {
+    let mut receiver = match Receiver::from_derive_input(&input) {
+        Ok(r) => r,
+        Err(e) => {
+            return proc_macro::TokenStream::from(
+                darling::Error::custom(format!("{}. {}", UNSUPPORTED_ERROR, e)).write_errors(),
+            )
+        }
+    };
+
+    // The column IDs are the names given to the fields by `ToJsValue`, so
+    // `#[js_value(...)]` is parsed with the same rules as that derive.
+    let js_value_receiver = match to_js_value::Receiver::from_derive_input(&input) {
+        Ok(r) => r,
+        Err(e) => return proc_macro::TokenStream::from(e.write_errors()),
+    };
+    if let ast::Data::Struct(f) = js_value_receiver.data {
+        receiver.col_ids = f.fields.iter().map(|f| f.js_name()).collect();
+    }
+
+    quote! {
+        #receiver
+    }
+    .into()
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(supports(struct_named))]
+struct Receiver {
+    ident: syn::Ident,
+    data: ast::Data<(), FieldReceiver>,
+    generics: Generics,
+
+    /// The column ID of each field, in declaration order.
+    #[darling(skip)]
+    col_ids: Vec<String>,
+}
+
+impl ToTokens for Receiver {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let root_crate = crate::root_crate();
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let mut arms = quote![];
+        if let ast::Data::Struct(ref f) = self.data {
+            for (field, col_id) in f.fields.iter().zip(&self.col_ids) {
+                if field.skip {
+                    continue;
+                }
+                let field_ident = field.ident.as_ref().unwrap();
+
+                arms.append_all(quote! {
+                    #col_id => ::std::option::Option::Some(
+                        #root_crate::row::ToCellValue::to_cell_value(&self.#field_ident)
+                    ),
+                });
+            }
+        }
+
+        tokens.append_all(quote! {
+            impl #impl_generics #root_crate::row::ColumnValues for #ident #ty_generics #where_clause {
+                fn column_value(&self, col_id: &str) -> ::std::option::Option<#root_crate::row::CellValue> {
+                    match col_id {
+                        #arms
+                        _ => ::std::option::Option::None,
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(column_values))]
+struct FieldReceiver {
+    ident: Option<syn::Ident>,
+
+    /// Exclude the field from the column values.
+    #[darling(default)]
+    skip: bool,
+}
@@ -75,6 +75,8 @@ impl ToTokens for StructReceiver {
 enum FieldType {
     OptionString,
     OptionOneOrManyString,
+    OptionValueOrCallbackString,
+    OptionValueOrCallback(syn::Type),
     OptionClosure,
     OptionOther(syn::Type),
 }
@@ -87,7 +89,7 @@ fn _type_str_parts(field_type: &syn::Type) -> (Vec<String>, Vec<syn::Type>) {
 
     loop {
         match type_ {
-            syn::Type::Path(ref type_path) if type_path.qself == None => {
+            syn::Type::Path(ref type_path) if type_path.qself.is_none() => {
                 if let Some(segment) = type_path.path.segments.last() {
                     parts.push(segment.ident.to_string());
                     match &segment.arguments {
@@ -134,6 +136,8 @@ impl FieldType {
         match remaining.as_slice() {
             ["String"] => FieldType::OptionString,
             ["OneOrMany", "String"] => FieldType::OptionOneOrManyString,
+            ["ValueOrCallback", "String"] => FieldType::OptionValueOrCallbackString,
+            ["ValueOrCallback", ..] => FieldType::OptionValueOrCallback(types[2].clone()),
             ["Closure", _] => FieldType::OptionClosure,
             _ => FieldType::OptionOther(types.get(1).cloned().unwrap()),
         }
@@ -206,6 +210,16 @@ impl FieldReceiver {
                 quote![value.as_ref().to_owned()],
                 quote![],
             ),
+            // The callback variant requires a dedicated, hand-written setter.
+            FieldType::OptionValueOrCallbackString => (
+                quote![],
+                quote![impl AsRef<str>],
+                quote![value.as_ref().to_string().into()],
+                quote![],
+            ),
+            FieldType::OptionValueOrCallback(inner_ty) => {
+                (quote![], quote![#inner_ty], quote![value.into()], quote![])
+            }
             FieldType::OptionOneOrManyString => (
                 quote![],
                 quote![impl AsRef<str>],
@@ -259,12 +273,7 @@ impl FieldReceiver {
     fn search_attrs(&self, name: &str) -> TokenStream {
         self.attrs
             .iter()
-            .filter(|attr| {
-                attr.path
-                    .segments
-                    .first()
-                    .map_or(false, |p| p.ident == name)
-            })
+            .filter(|attr| attr.path.segments.first().is_some_and(|p| p.ident == name))
             .map(|attr| {
                 quote![
                     #attr
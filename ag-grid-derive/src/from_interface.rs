@@ -1,7 +1,7 @@
 use darling::{ast, FromDeriveInput, FromField};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::{quote, ToTokens, TokenStreamExt};
-use syn::{DeriveInput, GenericArgument};
+use syn::{parse_quote, DeriveInput, GenericArgument, Generics};
 
 const UNSUPPORTED_ERROR: &str =
     r#"FromInterface can only be derived for structs with named fields"#;
@@ -30,6 +30,10 @@ struct StructReceiver {
     /// The body of the struct or enum. We don't care about enum fields
     /// because we accept only named structs. Hence the first type is null.
     data: ast::Data<(), FieldReceiver>,
+
+    /// Any type parameters are expected to be row data, which is decoded from
+    /// the interface with `FromJsValue`.
+    generics: Generics,
 }
 
 impl ToTokens for StructReceiver {
@@ -37,6 +41,18 @@ impl ToTokens for StructReceiver {
         let ident = &self.ident;
         let interface_ident = Ident::new(&format!("I{}", ident), proc_macro2::Span::call_site());
 
+        let mut generics = self.generics.clone();
+        let type_params: Vec<Ident> = generics
+            .type_params_mut()
+            .map(|param| {
+                param
+                    .bounds
+                    .push(parse_quote!(ag_grid_core::convert::FromJsValue));
+                param.ident.clone()
+            })
+            .collect();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
         let mut assignments = quote![];
 
         match self.data {
@@ -44,6 +60,21 @@ impl ToTokens for StructReceiver {
                 for field in f.fields.iter() {
                     let field_name = field.ident.as_ref().unwrap();
 
+                    if uses_type_params(field.ty.to_token_stream(), &type_params) {
+                        let error = format!("failed to decode `{field_name}`: ");
+                        assignments.append_all(quote!(
+                            #field_name: ag_grid_core::convert::FromJsValue::from_js_value(
+                                &i.#field_name()
+                            )
+                            .map_err(|e| {
+                                ag_grid_core::convert::FromJsValueError::new(
+                                    ::std::format!("{}{}", #error, e)
+                                )
+                            })?,
+                        ));
+                        continue;
+                    }
+
                     let (type_ident, ident_args) = match field.ty {
                         syn::Type::Path(ref type_path) => {
                             let path_end = type_path
@@ -96,15 +127,31 @@ impl ToTokens for StructReceiver {
             _ => unreachable!(),
         }
 
-        tokens.append_all(quote! {
-            impl From<&#interface_ident> for #ident {
-                fn from(i: &#interface_ident) -> Self {
-                    Self {
-                        #assignments
+        // Decoding row data can fail, so generic structs are only fallibly
+        // convertible from their interface.
+        if type_params.is_empty() {
+            tokens.append_all(quote! {
+                impl #impl_generics From<&#interface_ident> for #ident #ty_generics #where_clause {
+                    fn from(i: &#interface_ident) -> Self {
+                        Self {
+                            #assignments
+                        }
                     }
                 }
-            }
-        });
+            });
+        } else {
+            tokens.append_all(quote! {
+                impl #impl_generics ::std::convert::TryFrom<&#interface_ident> for #ident #ty_generics #where_clause {
+                    type Error = ag_grid_core::convert::FromJsValueError;
+
+                    fn try_from(i: &#interface_ident) -> ::std::result::Result<Self, Self::Error> {
+                        ::std::result::Result::Ok(Self {
+                            #assignments
+                        })
+                    }
+                }
+            });
+        }
     }
 }
 
@@ -117,3 +164,13 @@ struct FieldReceiver {
     /// The type of the field
     ty: syn::Type,
 }
+
+/// Return `true` if any of the given type parameters appear in the tokens of a
+/// type.
+fn uses_type_params(tokens: TokenStream, type_params: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => type_params.contains(&ident),
+        TokenTree::Group(group) => uses_type_params(group.stream(), type_params),
+        _ => false,
+    })
+}
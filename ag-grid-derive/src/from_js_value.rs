@@ -0,0 +1,121 @@
+use darling::{ast, FromDeriveInput};
+use quote::{quote, TokenStreamExt};
+use syn::{parse_quote, DeriveInput};
+
+use crate::{
+    root_crate,
+    to_js_value::{AltValue, Receiver},
+};
+
+const UNSUPPORTED_ERROR: &str =
+    r#"FromJsValue can only be derived for structs with named fields or enums"#;
+
+pub(crate) fn from_js_value_impl(input: DeriveInput) -> proc_macro::TokenStream {
+    // The attributes are shared with `ToJsValue`, so that a value survives a
+    // round trip unchanged.
+    let receiver = match Receiver::from_derive_input(&input) {
+        Ok(r) => r,
+        Err(e) => {
+            return proc_macro::TokenStream::from(
+                darling::Error::custom(format!("{}. {}", UNSUPPORTED_ERROR, e)).write_errors(),
+            )
+        }
+    };
+
+    let root_crate = root_crate();
+    let ident = &receiver.ident;
+
+    let mut generics = receiver.generics.clone();
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(parse_quote!(#root_crate::convert::FromJsValue));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match receiver.data {
+        ast::Data::Struct(ref f) => {
+            let mut fields = quote![];
+
+            for field in f.fields.iter() {
+                let field_ident = field.ident.as_ref().unwrap();
+                let js_name = field.js_name();
+                let error = format!("failed to decode `{js_name}`: ");
+
+                fields.append_all(quote! {
+                    #field_ident: #root_crate::convert::FromJsValue::from_js_value(&obj.get(#js_name))
+                        .map_err(|e| #root_crate::convert::FromJsValueError::new(::std::format!("{}{}", #error, e)))?,
+                });
+            }
+
+            quote! {
+                if !value.is_object() {
+                    return ::std::result::Result::Err(#root_crate::convert::FromJsValueError::invalid_type("an object", value));
+                }
+                let obj = wasm_bindgen::JsCast::unchecked_ref::<#root_crate::imports::ObjectExt>(value);
+                ::std::result::Result::Ok(Self {
+                    #fields
+                })
+            }
+        }
+        ast::Data::Enum(ref v) => {
+            let mut literals = quote![];
+            let mut arms = quote![];
+            let mut expected = Vec::new();
+
+            for v in v {
+                let variant_ident = &v.ident;
+                match v.serialize_as {
+                    Some(ref alt) => {
+                        let (check, name) = match alt {
+                            AltValue::Null => (quote!(value.is_null()), "null"),
+                            AltValue::Undefined => (quote!(value.is_undefined()), "undefined"),
+                            AltValue::True => (
+                                quote!(value.as_bool() == ::std::option::Option::Some(true)),
+                                "true",
+                            ),
+                            AltValue::False => (
+                                quote!(value.as_bool() == ::std::option::Option::Some(false)),
+                                "false",
+                            ),
+                        };
+                        literals.append_all(quote! {
+                            if #check {
+                                return ::std::result::Result::Ok(Self::#variant_ident);
+                            }
+                        });
+                        expected.push(name.to_string());
+                    }
+                    None => {
+                        let js_name = v.js_name();
+                        arms.append_all(quote! {
+                            ::std::option::Option::Some(#js_name) => ::std::result::Result::Ok(Self::#variant_ident),
+                        });
+                        expected.push(format!("`{js_name}`"));
+                    }
+                }
+            }
+
+            let expected = format!("one of {}", expected.join(", "));
+
+            quote! {
+                #literals
+                match value.as_string().as_deref() {
+                    #arms
+                    _ => ::std::result::Result::Err(#root_crate::convert::FromJsValueError::invalid_type(#expected, value)),
+                }
+            }
+        }
+    };
+
+    quote! {
+        impl #impl_generics #root_crate::convert::FromJsValue for #ident #ty_generics #where_clause {
+            fn from_js_value(
+                value: &wasm_bindgen::JsValue,
+            ) -> ::std::result::Result<Self, #root_crate::convert::FromJsValueError> {
+                #body
+            }
+        }
+    }
+    .into()
+}
@@ -1,9 +1,31 @@
+// `darling`'s `#[darling(default)]` expands to code which trips this lint.
+#![allow(clippy::manual_unwrap_or_default)]
+
+use proc_macro2::TokenStream;
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::{format_ident, quote};
 use syn::DeriveInput;
 
+mod column_values;
 mod field_setter;
 mod from_interface;
+mod from_js_value;
 mod to_js_value;
 
+/// The path to `ag_grid_rs` to use in generated code. Within `ag_grid_rs`
+/// itself, this relies on the `extern crate self as ag_grid_rs;` alias, so
+/// that the same path also works from its tests and examples.
+fn root_crate() -> TokenStream {
+    let found_crate = crate_name("ag-grid-rs").expect("ag-grid-rs is present in `Cargo.toml`");
+    match found_crate {
+        FoundCrate::Itself => quote!(ag_grid_rs),
+        FoundCrate::Name(name) => {
+            let ident = format_ident!("{}", name);
+            quote!(#ident)
+        }
+    }
+}
+
 #[proc_macro_derive(FieldSetter, attributes(field_setter))]
 pub fn field_setter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse::<DeriveInput>(input).unwrap();
@@ -24,7 +46,8 @@ pub fn field_setter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// types also implement `ToJsValue`. Most primitive types have a `ToJsValue`
 /// implemntation already. Given the following struct,
 ///
-/// ```rust
+/// ```rust,no_run
+/// # use ag_grid_rs::ToJsValue;
 /// #[derive(ToJsValue)]
 /// struct Data {
 ///     first_value: String,
@@ -34,11 +57,17 @@ pub fn field_setter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// the following equivalent implementation would be generated:
 ///
-/// ```rust
+/// ```rust,no_run
+/// # use ag_grid_rs::{convert::ToJsValue, imports::ObjectExt};
+/// # use wasm_bindgen::JsValue;
+/// # struct Data {
+/// #     first_value: String,
+/// #     second_value: bool,
+/// # }
 /// impl ToJsValue for Data {
 ///     fn to_js_value(&self) -> JsValue {
-///         let obj = js_sys::Object::new();
-///         // We actually use a custom implementation of Object which has a helper `set` method.
+///         // `ObjectExt` is a `js_sys::Object` with a helper `set` method.
+///         let obj = ObjectExt::new();
 ///         obj.set("firstValue", self.first_value.to_js_value());
 ///         obj.set("secondValue", self.second_value.to_js_value());
 ///         obj.into()
@@ -59,30 +88,44 @@ pub fn field_setter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// At the field-level:
 /// * `#[js_value(rename = "...")]` - override the default camelCase name for
 ///   the serialized field.
+/// * `#[js_value(row_id)]` - additionally implement the `RowId` trait, using
+///   the `to_string()` value of this field as the ID of the row. At most one
+///   field may be marked.
 ///
 /// ## Fieldless enums
 ///
 /// A fieldless enum, such as
 ///
-/// ```rust
-/// derive(ToJsValue)
+/// ```rust,no_run
+/// # use ag_grid_rs::ToJsValue;
+/// #[derive(ToJsValue)]
 /// enum MoonPhase {
 ///     New,
 ///     FirstQuarter,
 ///     ThirdQuarter,
-///     Full     
+///     Full,
 /// }
 /// ```
 ///
 /// would produce an implementation equivalent to:
 ///
-/// ```rust
+/// ```rust,no_run
+/// # use ag_grid_rs::convert::ToJsValue;
+/// # use wasm_bindgen::JsValue;
+/// # enum MoonPhase {
+/// #     New,
+/// #     FirstQuarter,
+/// #     ThirdQuarter,
+/// #     Full,
+/// # }
 /// impl ToJsValue for MoonPhase {
-///     fn to_js_value(&self) -> JsValue{
-///         Self::New => JsValue::from_str("new"),
-///         Self::New => JsValue::from_str("firstQuarter"),
-///         Self::New => JsValue::from_str("ThirdQuarter"),
-///         Self::New => JsValue::from_str("full"),
+///     fn to_js_value(&self) -> JsValue {
+///         match self {
+///             Self::New => JsValue::from_str("new"),
+///             Self::FirstQuarter => JsValue::from_str("firstQuarter"),
+///             Self::ThirdQuarter => JsValue::from_str("thirdQuarter"),
+///             Self::Full => JsValue::from_str("full"),
+///         }
 ///     }
 /// }
 /// ```
@@ -107,6 +150,88 @@ pub fn to_js_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     to_js_value::to_js_value_impl(input)
 }
 
+/// Automatically derive the `ColumnValues` trait, which exposes the value of
+/// each field by column ID so that rows can be filtered and sorted in memory.
+///
+/// The macro can be applied to structs with named fields where all field types
+/// implement `ToCellValue`. Given the following struct,
+///
+/// ```rust,no_run
+/// # use ag_grid_rs::ColumnValues;
+/// #[derive(ColumnValues)]
+/// struct Data {
+///     first_value: String,
+///     second_value: Option<u32>,
+/// }
+/// ```
+///
+/// the following equivalent implementation would be generated:
+///
+/// ```rust,no_run
+/// # use ag_grid_rs::row::{CellValue, ColumnValues, ToCellValue};
+/// # struct Data {
+/// #     first_value: String,
+/// #     second_value: Option<u32>,
+/// # }
+/// impl ColumnValues for Data {
+///     fn column_value(&self, col_id: &str) -> Option<CellValue> {
+///         match col_id {
+///             "firstValue" => Some(self.first_value.to_cell_value()),
+///             "secondValue" => Some(self.second_value.to_cell_value()),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+///
+/// As with `ToJsValue`, the column IDs are the camelCase field names.
+///
+/// ### Supported attributes
+///
+/// At the field-level:
+/// * `#[js_value(rename = "...")]` - override the default camelCase column ID.
+///   This is the same attribute used by `ToJsValue`, so that the column ID
+///   matches the serialized field.
+/// * `#[column_values(skip)]` - exclude the field, e.g. if its type does not
+///   implement `ToCellValue`.
+#[proc_macro_derive(ColumnValues, attributes(js_value, column_values))]
+pub fn column_values(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse::<DeriveInput>(input).unwrap();
+    column_values::column_values_impl(input)
+}
+
+/// Automatically derive the `FromJsValue` trait to enable the annotated type
+/// to be decoded from a `wasm_bindgen::JsValue`, such as row data handed back
+/// by the grid.
+///
+/// The macro can be applied either to structs with named fields, or fieldless
+/// enums. It is the inverse of the `ToJsValue` derive, and accepts the same
+/// `#[js_value(...)]` attributes, so that a value which is serialized with
+/// `ToJsValue` survives a round trip unchanged. Given the following struct,
+///
+/// ```rust,no_run
+/// # use ag_grid_rs::FromJsValue;
+/// #[derive(FromJsValue)]
+/// struct Data {
+///     first_value: String,
+///     #[js_value(rename = "other")]
+///     second_value: Option<bool>,
+/// }
+/// ```
+///
+/// the `firstValue` and `other` properties of a JavaScript object are decoded
+/// into the respective fields. Missing properties decode to `None`, if the
+/// field is an `Option<T>`, and are an error otherwise.
+///
+/// Fieldless enums are decoded from the camelCase name of each variant, or its
+/// `rename`. Variants with `serialize_as` are decoded from the chosen literal
+/// JavaScript value.
+#[proc_macro_derive(FromJsValue, attributes(js_value))]
+pub fn from_js_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse::<DeriveInput>(input).unwrap();
+    from_js_value::from_js_value_impl(input)
+}
+
 #[proc_macro_derive(FromInterface)]
 pub fn from_interface(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse::<DeriveInput>(input).unwrap();
@@ -1,17 +1,8 @@
 use convert_case::{Case, Casing};
 use darling::{ast, FromDeriveInput, FromField, FromMeta, FromVariant};
 use proc_macro2::TokenStream;
-use proc_macro_crate::{crate_name, FoundCrate};
 use quote::{quote, ToTokens, TokenStreamExt};
-use syn::{DeriveInput, Generics, Type};
-
-fn root_crate() -> TokenStream {
-    let found_crate = crate_name("ag-grid-rs").expect("ag-grid-rs is present in `Cargo.toml`");
-    match found_crate {
-        FoundCrate::Itself => quote!(ag_grid_core),
-        FoundCrate::Name(_) => quote!(ag_grid_rs),
-    }
-}
+use syn::{parse_quote, DeriveInput, Generics, Type};
 
 const UNSUPPORTED_ERROR: &str =
     r#"ToJsValue can only be derived for structs with named fields or enums"#;
@@ -32,10 +23,11 @@ pub(crate) fn to_js_value_impl(input: DeriveInput) -> proc_macro::TokenStream {
 }
 
 #[derive(Debug, FromDeriveInput)]
-struct Receiver {
-    ident: syn::Ident,
-    data: ast::Data<VariantReceiver, FieldReceiver>,
-    generics: Generics,
+#[darling(attributes(js_value))]
+pub(crate) struct Receiver {
+    pub(crate) ident: syn::Ident,
+    pub(crate) data: ast::Data<VariantReceiver, FieldReceiver>,
+    pub(crate) generics: Generics,
 
     /// When applied to structs, fields of type `Option<T>` are not serialized.
     /// By default, `Option<T>` will serialize to `JsValue::null()` if the value
@@ -46,14 +38,36 @@ struct Receiver {
 
 impl ToTokens for Receiver {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let root_crate = root_crate();
+        let root_crate = crate::root_crate();
         let ident = &self.ident;
-        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let mut generics = self.generics.clone();
+        for param in generics.type_params_mut() {
+            param
+                .bounds
+                .push(parse_quote!(#root_crate::convert::ToJsValue));
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let mut row_id = quote![];
 
         let body = match self.data {
             ast::Data::Struct(ref f) => {
                 let mut serialized_fields = quote![];
 
+                let row_id_fields = f.fields.iter().filter(|f| f.row_id).collect::<Vec<_>>();
+                match row_id_fields.as_slice() {
+                    [] => {}
+                    [field] => row_id.append_all(self.row_id(field)),
+                    _ => {
+                        tokens.append_all(
+                            darling::Error::custom("only one field can be marked as `row_id`")
+                                .write_errors(),
+                        );
+                        return;
+                    }
+                }
+
                 for field in f.fields.iter() {
                     serialized_fields.append_all(field.serialize(self.skip_serializing_none));
                 }
@@ -86,27 +100,60 @@ impl ToTokens for Receiver {
                     #body
                 }
             }
+
+            #row_id
         });
     }
 }
 
+impl Receiver {
+    fn row_id(&self, field: &FieldReceiver) -> TokenStream {
+        let root_crate = crate::root_crate();
+        let ident = &self.ident;
+        let field_ident = field.ident.as_ref().unwrap();
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics #root_crate::row::RowId for #ident #ty_generics #where_clause {
+                fn row_id(&self) -> ::std::string::String {
+                    ::std::string::ToString::to_string(&self.#field_ident)
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, FromField)]
 #[darling(attributes(js_value))]
-struct FieldReceiver {
-    ident: Option<syn::Ident>,
+pub(crate) struct FieldReceiver {
+    pub(crate) ident: Option<syn::Ident>,
     ty: syn::Type,
 
     /// Allow individual fields to have their serialized name over-ridden
     rename: Option<String>,
+
+    /// Use this field as the ID of the row, by implementing `RowId`
+    #[darling(default)]
+    row_id: bool,
 }
 
 impl FieldReceiver {
+    /// The name of the field in the JavaScript object.
+    pub(crate) fn js_name(&self) -> String {
+        match self.rename.as_ref() {
+            Some(name) => name.clone(),
+            None => self
+                .ident
+                .as_ref()
+                .unwrap()
+                .to_string()
+                .to_case(Case::Camel),
+        }
+    }
+
     fn serialize(&self, skip_serializing_none: bool) -> TokenStream {
         let field_ident = self.ident.as_ref().unwrap();
-        let js_name = match self.rename.as_ref() {
-            Some(name) => name.clone(),
-            None => field_ident.to_string().to_case(Case::Camel),
-        };
+        let js_name = self.js_name();
 
         let is_option = is_option(&self.ty);
 
@@ -135,7 +182,7 @@ impl FieldReceiver {
 }
 
 #[derive(FromMeta, Debug)]
-enum AltValue {
+pub(crate) enum AltValue {
     Null,
     True,
     False,
@@ -144,23 +191,28 @@ enum AltValue {
 
 #[derive(FromVariant, Debug)]
 #[darling(attributes(js_value))]
-struct VariantReceiver {
-    ident: syn::Ident,
+pub(crate) struct VariantReceiver {
+    pub(crate) ident: syn::Ident,
 
     /// Allow individual variants to have their serialized name over-ridden
     rename: Option<String>,
 
     /// Allow an override for certain primitive JsValues
-    serialize_as: Option<AltValue>,
+    pub(crate) serialize_as: Option<AltValue>,
 }
 
 impl VariantReceiver {
+    /// The string the variant is serialized to, unless `serialize_as` is set.
+    pub(crate) fn js_name(&self) -> String {
+        match self.rename.as_ref() {
+            Some(name) => name.clone(),
+            None => self.ident.to_string().to_case(Case::Camel),
+        }
+    }
+
     fn serialize(&self) -> TokenStream {
         let variant_ident = &self.ident;
-        let js_name = match self.rename.as_ref() {
-            Some(name) => name.clone(),
-            None => variant_ident.to_string().to_case(Case::Camel),
-        };
+        let js_name = self.js_name();
 
         let serialized_value = self
             .serialize_as
@@ -1,17 +1,48 @@
 //! A collection of parameter types passed to callback functions.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
-use ag_grid_core::imports::ObjectExt;
+use ag_grid_core::{convert::ToJsValue, imports::log};
 use ag_grid_derive::FromInterface;
 use js_sys::{Function, Object};
 use wasm_bindgen::{prelude::*, JsCast};
 
 use crate::{
-    filter::{CombinedFilterModel, FilterModel, FilterModelType},
+    column::Column,
+    filter::{filter_model_from_object, FilterModelType, FilterParseError},
+    row::{compare_rows, passes_filter, ColumnValues},
     sort::{ISortModelItem, SortModelItem},
 };
 
+/// A Rust closure which the grid calls with the raw parameters of a callback.
+pub(crate) type Callback = Closure<dyn FnMut(JsValue) -> JsValue>;
+
+/// Wrap a closure taking typed parameters into a [`Callback`] which can be
+/// passed to the grid. The returned closure must be kept alive for as long as
+/// the grid may call it.
+///
+/// If the parameters cannot be decoded, e.g. because the row data does not
+/// match `T`, the error is logged and `undefined` is returned to the grid
+/// without calling the closure.
+pub(crate) fn callback<I, P, R, F>(mut f: F) -> Callback
+where
+    I: JsCast + 'static,
+    P: for<'a> TryFrom<&'a I> + 'static,
+    for<'a> <P as TryFrom<&'a I>>::Error: fmt::Display,
+    R: ToJsValue + 'static,
+    F: FnMut(P) -> R + 'static,
+{
+    Closure::<dyn FnMut(JsValue) -> JsValue>::new(move |js_params: JsValue| {
+        match P::try_from(js_params.unchecked_ref::<I>()) {
+            Ok(params) => f(params).to_js_value(),
+            Err(e) => {
+                log(&format!("Error decoding callback params: {e}"));
+                JsValue::undefined()
+            }
+        }
+    })
+}
+
 #[wasm_bindgen]
 extern "C" {
     pub(crate) type IHeaderValueGetterParams;
@@ -28,6 +59,346 @@ pub struct HeaderValueGetterParams {
     pub location: Option<String>,
 }
 
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IValueGetterParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &IValueGetterParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &IValueGetterParams) -> Column;
+}
+
+impl IValueGetterParams {
+    fn col_id(&self) -> String {
+        self.column().col_id()
+    }
+}
+
+/// Parameters passed to the closure in
+/// [`ColumnDef::value_getter_fn`][`crate::ColumnDef::value_getter_fn`].
+#[derive(Debug, FromInterface)]
+pub struct ValueGetterParams<T> {
+    /// The data of the row, if any.
+    pub data: Option<T>,
+    /// The ID of the column.
+    pub col_id: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IValueFormatterParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &IValueFormatterParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &IValueFormatterParams) -> Column;
+
+    #[wasm_bindgen(method, getter)]
+    fn value(this: &IValueFormatterParams) -> JsValue;
+}
+
+impl IValueFormatterParams {
+    fn col_id(&self) -> String {
+        self.column().col_id()
+    }
+}
+
+/// Parameters passed to the closure in
+/// [`ColumnDef::value_formatter_fn`][`crate::ColumnDef::value_formatter_fn`].
+#[derive(Debug, FromInterface)]
+pub struct ValueFormatterParams<T> {
+    /// The data of the row, if any.
+    pub data: Option<T>,
+    /// The ID of the column.
+    pub col_id: String,
+    /// The raw value of the cell which is to be formatted.
+    pub value: JsValue,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ICellCallbackParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &ICellCallbackParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &ICellCallbackParams) -> Column;
+}
+
+impl ICellCallbackParams {
+    fn col_id(&self) -> String {
+        self.column().col_id()
+    }
+}
+
+/// Parameters passed to the closures which decide a per-cell setting, such as
+/// [`ColumnDef::editable_fn`][`crate::ColumnDef::editable_fn`].
+#[derive(Debug, FromInterface)]
+pub struct CellCallbackParams<T> {
+    /// The data of the row, if any.
+    pub data: Option<T>,
+    /// The ID of the column.
+    pub col_id: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IHeaderCheckboxSelectionParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &IHeaderCheckboxSelectionParams) -> Column;
+}
+
+impl IHeaderCheckboxSelectionParams {
+    fn col_id(&self) -> String {
+        self.column().col_id()
+    }
+}
+
+/// Parameters passed to the closure in
+/// [`ColumnDef::header_checkbox_selection_fn`][`crate::ColumnDef::header_checkbox_selection_fn`].
+#[derive(Debug, FromInterface)]
+pub struct HeaderCheckboxSelectionParams {
+    /// The ID of the column.
+    pub col_id: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Any object with a `rowIndex`, such as a row node.
+    type IRowIndex;
+
+    #[wasm_bindgen(method, getter, js_name = rowIndex)]
+    fn row_index(this: &IRowIndex) -> Option<u32>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IColSpanParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &IColSpanParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &IColSpanParams) -> Column;
+
+    #[wasm_bindgen(method, getter)]
+    fn node(this: &IColSpanParams) -> IRowIndex;
+}
+
+impl IColSpanParams {
+    fn row_index(&self) -> Option<u32> {
+        self.node().row_index()
+    }
+}
+
+/// Parameters passed to the closure in
+/// [`ColumnDef::col_span_fn`][`crate::ColumnDef::col_span_fn`].
+#[derive(Debug, FromInterface)]
+pub struct ColSpanParams<T> {
+    /// The data of the row, if any.
+    pub data: Option<T>,
+    /// The column of the cell.
+    pub column: Column,
+    /// The index of the row of the cell.
+    pub row_index: Option<u32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IRowSpanParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &IRowSpanParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &IRowSpanParams) -> Column;
+
+    #[wasm_bindgen(method, getter)]
+    fn node(this: &IRowSpanParams) -> IRowIndex;
+}
+
+impl IRowSpanParams {
+    fn row_index(&self) -> Option<u32> {
+        self.node().row_index()
+    }
+}
+
+/// Parameters passed to the closure in
+/// [`ColumnDef::row_span_fn`][`crate::ColumnDef::row_span_fn`].
+#[derive(Debug, FromInterface)]
+pub struct RowSpanParams<T> {
+    /// The data of the row, if any.
+    pub data: Option<T>,
+    /// The column of the cell.
+    pub column: Column,
+    /// The index of the row of the cell.
+    pub row_index: Option<u32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ICellRendererParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn value(this: &ICellRendererParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = valueFormatted)]
+    fn value_formatted(this: &ICellRendererParams) -> Option<String>;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &ICellRendererParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &ICellRendererParams) -> Column;
+
+    #[wasm_bindgen(method, getter)]
+    fn node(this: &ICellRendererParams) -> IRowIndex;
+}
+
+impl ICellRendererParams {
+    fn row_index(&self) -> Option<u32> {
+        self.node().row_index()
+    }
+}
+
+/// Parameters passed to [`CellRenderer::init`][`crate::components::CellRenderer::init`]
+/// and [`CellRenderer::refresh`][`crate::components::CellRenderer::refresh`].
+#[derive(Debug, FromInterface)]
+pub struct CellRendererParams<T> {
+    /// The value of the cell.
+    pub value: JsValue,
+    /// The value of the cell after it has been through the column's value
+    /// formatter, if it has one.
+    pub value_formatted: Option<String>,
+    /// The data of the row, if any.
+    pub data: Option<T>,
+    /// The column of the cell.
+    pub column: Column,
+    /// The index of the row of the cell.
+    pub row_index: Option<u32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ICellEditorParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn value(this: &ICellEditorParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = eventKey)]
+    fn event_key(this: &ICellEditorParams) -> Option<String>;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &ICellEditorParams) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &ICellEditorParams) -> Column;
+
+    #[wasm_bindgen(method, getter, js_name = rowIndex)]
+    fn row_index(this: &ICellEditorParams) -> Option<u32>;
+}
+
+/// Parameters passed to [`CellEditor::init`][`crate::components::CellEditor::init`].
+#[derive(Debug, FromInterface)]
+pub struct CellEditorParams<T> {
+    /// The value of the cell before editing started.
+    pub value: JsValue,
+    /// The key which started editing, if editing was started by a key press.
+    pub event_key: Option<String>,
+    /// The data of the row, if any.
+    pub data: Option<T>,
+    /// The column of the cell.
+    pub column: Column,
+    /// The index of the row of the cell.
+    pub row_index: Option<u32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IFilterComponentParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &IFilterComponentParams) -> Column;
+
+    #[wasm_bindgen(method, getter, js_name = filterChangedCallback)]
+    fn filter_changed_callback(this: &IFilterComponentParams) -> Function;
+}
+
+/// Parameters passed to
+/// [`FilterComponent::init`][`crate::components::FilterComponent::init`].
+#[derive(Debug, Clone, FromInterface)]
+pub struct FilterComponentParams {
+    /// The column the filter is applied to.
+    pub column: Column,
+    filter_changed_callback: Function,
+}
+
+impl FilterComponentParams {
+    /// Tell the grid that the filter has changed, so that it filters the rows
+    /// again.
+    pub fn filter_changed(&self) {
+        if let Err(e) = self.filter_changed_callback.call0(&JsValue::null()) {
+            log(&format!("Error calling filterChangedCallback: {e:?}"));
+        }
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IDoesFilterPassParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &IDoesFilterPassParams) -> JsValue;
+}
+
+/// Parameters passed to
+/// [`FilterComponent::does_filter_pass`][`crate::components::FilterComponent::does_filter_pass`].
+#[derive(Debug, FromInterface)]
+pub struct DoesFilterPassParams<T> {
+    /// The data of the row being tested.
+    pub data: T,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ISetFilterValuesParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &ISetFilterValuesParams) -> Column;
+
+    #[wasm_bindgen(method, getter)]
+    pub(crate) fn success(this: &ISetFilterValuesParams) -> Function;
+}
+
+/// Parameters passed to the closure in
+/// [`SetFilterParams::values_fn`][`crate::filter::SetFilterParams::values_fn`].
+#[derive(Debug, FromInterface)]
+pub struct SetFilterValuesParams {
+    /// The column the filter is applied to.
+    pub column: Column,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IGetRowIdParams;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &IGetRowIdParams) -> JsValue;
+}
+
+/// Parameters passed to the closure in
+/// [`GridOptions::get_row_id`][`crate::GridOptions::get_row_id`].
+#[derive(Debug, FromInterface)]
+pub(crate) struct GetRowIdParams<T> {
+    /// The data of the row.
+    pub data: T,
+}
+
 #[wasm_bindgen]
 extern "C" {
     pub(crate) type IGetRowsParams;
@@ -66,33 +437,42 @@ pub struct GetRowsParams {
     pub filter_model: HashMap<String, FilterModelType>,
 }
 
-impl From<&IGetRowsParams> for GetRowsParams {
-    fn from(i: &IGetRowsParams) -> Self {
-        Self {
+impl TryFrom<&IGetRowsParams> for GetRowsParams {
+    type Error = FilterParseError;
+
+    fn try_from(i: &IGetRowsParams) -> Result<Self, Self::Error> {
+        Ok(Self {
             start_row: i.start_row(),
             end_row: i.end_row(),
             sort_model: i.sort_model().iter().map(SortModelItem::from).collect(),
-            filter_model: {
-                let filter_object = i.filter_model().unchecked_into::<ObjectExt>();
-                let mut filters = Vec::new();
-
-                for (col, filter) in filter_object.entries() {
-                    let filter = filter.unchecked_into::<ObjectExt>();
-
-                    let filter = if filter.has_own_property(&"operator".into()) {
-                        let filter = CombinedFilterModel::from_object(&filter);
-                        FilterModelType::Combined(filter)
-                    } else {
-                        let filter = FilterModel::from_object(&filter);
-                        FilterModelType::Single(filter)
-                    };
-
-                    let filter = (col, filter);
-                    filters.push(filter);
-                }
-
-                filters.into_iter().collect()
-            },
-        }
+            filter_model: filter_model_from_object(i.filter_model().unchecked_ref())?,
+        })
+    }
+}
+
+impl GetRowsParams {
+    /// Filter and sort the given rows in memory, then keep only the block of
+    /// rows from `start_row` to `end_row`.
+    ///
+    /// Returns the number of rows which passed the filter model, which is the
+    /// last row index to report to the grid. Custom filter models are ignored.
+    /// See the [`row`][crate::row] module for more details.
+    pub fn apply<T: ColumnValues>(&self, rows: &mut Vec<T>) -> usize {
+        // Sort the filter model so that rows are evaluated in a stable order.
+        let mut filter_model = self.filter_model.iter().collect::<Vec<_>>();
+        filter_model.sort_by_key(|(col_id, _)| *col_id);
+
+        rows.retain(|row| {
+            filter_model
+                .iter()
+                .all(|(col_id, model)| passes_filter(row, col_id, model))
+        });
+        rows.sort_by(|a, b| compare_rows(a, b, &self.sort_model));
+
+        let count = rows.len();
+        rows.truncate(self.end_row as usize);
+        rows.drain(..(self.start_row as usize).min(rows.len()));
+
+        count
     }
 }
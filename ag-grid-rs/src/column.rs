@@ -1,17 +1,340 @@
 //! Types pertaining to the grid columns.
 
+use ag_grid_core::convert::{FromJsValue, ToJsValue};
 use ag_grid_derive::{FieldSetter, ToJsValue};
+use js_sys::{Array, Object};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+#[cfg(feature = "serde")]
+use crate::shared::deserialize_some;
 pub use crate::shared::SortMethod;
-use crate::{callbacks::IHeaderValueGetterParams, types::OneOrMany};
+use crate::{
+    callbacks::{
+        callback, CellCallbackParams, ColSpanParams, HeaderCheckboxSelectionParams,
+        ICellCallbackParams, IColSpanParams, IHeaderCheckboxSelectionParams,
+        IHeaderValueGetterParams, IRowSpanParams, IValueFormatterParams, IValueGetterParams,
+        RowSpanParams, ValueFormatterParams, ValueGetterParams,
+    },
+    components::{
+        cell_editor_class, cell_renderer_class, filter_component_class, CellEditor, CellRenderer,
+        ComponentClass, FilterComponent,
+    },
+    filter::FilterParams,
+    types::{OneOrMany, ValueOrCallback},
+};
 
 #[wasm_bindgen]
 extern "C" {
     /// A handle for the AG Grid [`Column API`].
     ///
     /// [`Column API`]: https://www.ag-grid.com/javascript-data-grid/column-api/
+    #[derive(Debug, Clone)]
     pub type ColumnApi;
+
+    #[wasm_bindgen(method)]
+    fn getColumn(this: &ColumnApi, key: &str) -> Option<Column>;
+
+    #[wasm_bindgen(method)]
+    fn getAllColumns(this: &ColumnApi) -> Option<Vec<Column>>;
+
+    #[wasm_bindgen(method)]
+    fn setColumnVisible(this: &ColumnApi, key: &str, visible: bool);
+
+    #[wasm_bindgen(method)]
+    fn setColumnsVisible(this: &ColumnApi, keys: Array, visible: bool);
+
+    #[wasm_bindgen(method)]
+    fn setColumnPinned(this: &ColumnApi, key: &str, pinned: JsValue);
+
+    #[wasm_bindgen(method)]
+    fn moveColumn(this: &ColumnApi, key: &str, to_index: u32);
+
+    #[wasm_bindgen(method)]
+    fn setColumnWidth(this: &ColumnApi, key: &str, new_width: u32);
+
+    #[wasm_bindgen(method)]
+    fn autoSizeColumns(this: &ColumnApi, keys: Array, skip_header: bool);
+
+    #[wasm_bindgen(method)]
+    fn autoSizeAllColumns(this: &ColumnApi, skip_header: bool);
+
+    #[wasm_bindgen(method)]
+    fn resetColumnState(this: &ColumnApi);
+
+    #[wasm_bindgen(method)]
+    fn getColumnState(this: &ColumnApi) -> Vec<IColumnState>;
+
+    #[wasm_bindgen(method)]
+    fn applyColumnState(this: &ColumnApi, params: JsValue) -> bool;
+}
+
+impl ColumnApi {
+    /// Get the column with the given column ID, if it exists.
+    pub fn get_column(&self, key: &str) -> Option<Column> {
+        Self::getColumn(self, key)
+    }
+
+    /// Get all of the grid's columns, in the order they were defined.
+    pub fn get_all_columns(&self) -> Vec<Column> {
+        Self::getAllColumns(self).unwrap_or_default()
+    }
+
+    /// Show or hide the column with the given column ID.
+    pub fn set_column_visible(&self, key: &str, visible: bool) {
+        Self::setColumnVisible(self, key, visible)
+    }
+
+    /// Show or hide each of the columns with the given column IDs.
+    pub fn set_columns_visible(&self, keys: &[&str], visible: bool) {
+        Self::setColumnsVisible(self, keys_to_array(keys), visible)
+    }
+
+    /// Pin the column with the given column ID to one side of the grid.
+    /// Use [`PinnedPosition::False`] to unpin the column.
+    pub fn set_column_pinned(&self, key: &str, pinned: PinnedPosition) {
+        Self::setColumnPinned(self, key, pinned.to_js_value())
+    }
+
+    /// Move the column with the given column ID to the given index.
+    pub fn move_column(&self, key: &str, to_index: u32) {
+        Self::moveColumn(self, key, to_index)
+    }
+
+    /// Set the width in pixels of the column with the given column ID.
+    pub fn set_column_width(&self, key: &str, new_width: u32) {
+        Self::setColumnWidth(self, key, new_width)
+    }
+
+    /// Auto-size each of the columns with the given column IDs to fit their
+    /// contents. Set `skip_header` to `true` to ignore the header when
+    /// calculating the widths.
+    pub fn auto_size_columns(&self, keys: &[&str], skip_header: bool) {
+        Self::autoSizeColumns(self, keys_to_array(keys), skip_header)
+    }
+
+    /// Auto-size all columns to fit their contents. Set `skip_header` to
+    /// `true` to ignore the header when calculating the widths.
+    pub fn auto_size_all_columns(&self, skip_header: bool) {
+        Self::autoSizeAllColumns(self, skip_header)
+    }
+
+    /// Reset the state of all columns back to their column definitions.
+    pub fn reset_column_state(&self) {
+        Self::resetColumnState(self)
+    }
+
+    /// Get the current state of every column, such as its width, position and
+    /// sort. The state can later be restored with
+    /// [`ColumnApi::apply_column_state`].
+    pub fn get_column_state(&self) -> Vec<ColumnState> {
+        Self::getColumnState(self)
+            .iter()
+            .map(ColumnState::from_js_state)
+            .collect()
+    }
+
+    /// Apply the given column state to the grid. Returns `false` if any of the
+    /// column states could not be applied, e.g. because the column ID was not
+    /// found.
+    pub fn apply_column_state(&self, params: ApplyColumnStateParams) -> bool {
+        Self::applyColumnState(self, params.to_js_value())
+    }
+}
+
+fn keys_to_array(keys: &[&str]) -> Array {
+    keys.iter().map(|key| JsValue::from_str(key)).collect()
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// A handle for an AG Grid [`Column`], as returned by the [`ColumnApi`].
+    ///
+    /// [`Column`]: https://www.ag-grid.com/javascript-data-grid/column-object/
+    #[derive(Debug, Clone)]
+    pub type Column;
+
+    #[wasm_bindgen(method)]
+    fn getColId(this: &Column) -> String;
+
+    #[wasm_bindgen(method)]
+    fn isVisible(this: &Column) -> bool;
+
+    #[wasm_bindgen(method)]
+    fn getActualWidth(this: &Column) -> u32;
+
+    #[wasm_bindgen(method)]
+    fn getSort(this: &Column) -> Option<String>;
+
+    #[wasm_bindgen(method)]
+    fn isPinned(this: &Column) -> bool;
+
+    #[wasm_bindgen(method)]
+    fn isFilterActive(this: &Column) -> bool;
+
+    #[wasm_bindgen(method)]
+    fn getColDef(this: &Column) -> Object;
+}
+
+impl Column {
+    /// The unique ID of the column.
+    pub fn col_id(&self) -> String {
+        Self::getColId(self)
+    }
+
+    /// Whether the column is currently visible.
+    pub fn is_visible(&self) -> bool {
+        Self::isVisible(self)
+    }
+
+    /// The current width of the column in pixels.
+    pub fn get_actual_width(&self) -> u32 {
+        Self::getActualWidth(self)
+    }
+
+    /// How the column is currently sorted. Returns [`SortMethod::Null`] if the
+    /// column is not sorted.
+    pub fn get_sort(&self) -> SortMethod {
+        SortMethod::from_js_sort(Self::getSort(self))
+    }
+
+    /// Whether the column is pinned to either side of the grid.
+    pub fn is_pinned(&self) -> bool {
+        Self::isPinned(self)
+    }
+
+    /// Whether the column currently has an active filter.
+    pub fn is_filter_active(&self) -> bool {
+        Self::isFilterActive(self)
+    }
+
+    /// The raw JavaScript column definition the grid is using for the column.
+    pub fn get_col_def(&self) -> Object {
+        Self::getColDef(self)
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IColumnState;
+
+    #[wasm_bindgen(method, getter, js_name = colId)]
+    fn col_id(this: &IColumnState) -> String;
+
+    #[wasm_bindgen(method, getter)]
+    fn width(this: &IColumnState) -> Option<u32>;
+
+    #[wasm_bindgen(method, getter)]
+    fn hide(this: &IColumnState) -> Option<bool>;
+
+    #[wasm_bindgen(method, getter)]
+    fn pinned(this: &IColumnState) -> Option<String>;
+
+    #[wasm_bindgen(method, getter)]
+    fn sort(this: &IColumnState) -> Option<String>;
+
+    #[wasm_bindgen(method, getter, js_name = sortIndex)]
+    fn sort_index(this: &IColumnState) -> Option<u32>;
+
+    #[wasm_bindgen(method, getter)]
+    fn flex(this: &IColumnState) -> Option<u32>;
+}
+
+/// The state of a single column, as returned by
+/// [`ColumnApi::get_column_state`].
+///
+/// When applying state, any field which is `None` is left unchanged on the
+/// column.
+#[derive(Debug, Clone, Default, ToJsValue)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[js_value(skip_serializing_none)]
+pub struct ColumnState {
+    /// The ID of the column the state applies to.
+    pub col_id: String,
+    /// The width of the column in pixels.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub width: Option<u32>,
+    /// Whether the column is hidden.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub hide: Option<bool>,
+    /// Which side of the grid the column is pinned to. An unpinned column is
+    /// represented by [`PinnedPosition::False`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            deserialize_with = "deserialize_some",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub pinned: Option<PinnedPosition>,
+    /// How the column is sorted. An unsorted column is represented by
+    /// [`SortMethod::Null`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            deserialize_with = "deserialize_some",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub sort: Option<SortMethod>,
+    /// The order in which the sort is applied when sorting by multiple
+    /// columns. `Some(None)` clears the sort index.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            deserialize_with = "deserialize_some",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub sort_index: Option<Option<u32>>,
+    /// The flex value of the column, if it is flexed.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub flex: Option<u32>,
+}
+
+impl ColumnState {
+    pub(crate) fn from_js_state(i: &IColumnState) -> Self {
+        Self {
+            col_id: i.col_id(),
+            width: i.width(),
+            hide: i.hide(),
+            pinned: Some(PinnedPosition::from_js_pinned(i.pinned())),
+            sort: Some(SortMethod::from_js_sort(i.sort())),
+            sort_index: Some(i.sort_index()),
+            flex: i.flex(),
+        }
+    }
+}
+
+/// Parameters for [`ColumnApi::apply_column_state`].
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct ApplyColumnStateParams {
+    #[field_setter(skip)]
+    state: Option<Vec<ColumnState>>,
+
+    /// Set to `true` to order the columns as they appear in the provided
+    /// state.
+    apply_order: Option<bool>,
+
+    /// State to apply to any column which does not appear in the provided
+    /// state. The [`ColumnState::col_id`] of the default state is ignored.
+    default_state: Option<ColumnState>,
+}
+
+impl ApplyColumnStateParams {
+    /// Create new parameters for applying the given column state.
+    pub fn new(state: Vec<ColumnState>) -> Self {
+        Self {
+            state: Some(state),
+            ..Default::default()
+        }
+    }
 }
 
 /// A customisable struct for defining a column.
@@ -33,20 +356,18 @@ pub struct ColumnDef {
     /// properties when you have a lot of common column properties.
     type_: Option<OneOrMany<String>>,
 
-    // TODO: support callback function
-    /// A function or expression that gets the value to be displayed from your
-    /// data.
-    value_getter: Option<String>,
+    /// An expression that gets the value to be displayed from your data. Use
+    /// [`ColumnDef::value_getter_fn`] to provide a closure instead.
+    value_getter: Option<ValueOrCallback<String>>,
 
-    // TODO: support callback function
-    /// A function or an expression to format a value. Not used for CSV export
-    /// or copy to clipboard; only for UI cell rendering.
-    value_formatter: Option<String>,
+    /// An expression to format a value. Not used for CSV export or copy to
+    /// clipboard; only for UI cell rendering. Use
+    /// [`ColumnDef::value_formatter_fn`] to provide a closure instead.
+    value_formatter: Option<ValueOrCallback<String>>,
 
-    /// Provide a reference data map to be used to map column values to their
-    /// respective value from the map.
+    // Provide a reference data map to be used to map column values to their
+    // respective value from the map.
     //ref_data: Option<HashMap<String, String>>,
-
     /// Set to `true` to display a disabled checkbox when row is not selectable
     /// and checkboxes are enabled.
     show_disabled_checkboxes: Option<bool>,
@@ -72,8 +393,18 @@ pub struct ColumnDef {
     suppress_movable: Option<bool>,
 
     // Editing
-    /// Set to `true` if this column is editable.
-    editable: Option<bool>, // TODO: add support for a callback function
+    /// Set to `true` if this column is editable. Use
+    /// [`ColumnDef::editable_fn`] to decide per row instead.
+    editable: Option<ValueOrCallback<bool>>,
+
+    /// The cell editor to use for this column. Set with
+    /// [`ColumnDef::cell_editor`].
+    #[field_setter(skip)]
+    cell_editor: Option<CellEditorType>,
+
+    /// Parameters for the cell editor. Set with [`ColumnDef::cell_editor`].
+    #[field_setter(skip)]
+    cell_editor_params: Option<JsValue>,
 
     /// Set to `true` to have the cell editor appear in a popup.
     cell_editor_popup: Option<bool>,
@@ -97,6 +428,9 @@ pub struct ColumnDef {
     /// filters.
     filter: Option<Filter>,
 
+    /// Parameters for the filter set with [`ColumnDef::filter`].
+    filter_params: Option<FilterParams>,
+
     /// Whether to display a floating filter for this column.
     floating_filter: Option<bool>,
     // TODO
@@ -129,8 +463,10 @@ pub struct ColumnDef {
     /// Set to `true` to disable showing the menu for this column header.
     suppress_menu: Option<bool>,
 
-    /// If `true`, a 'select all' checkbox will be put into the header.
-    header_checkbox_selection: Option<bool>, // TODO: add support for a callback function
+    /// If `true`, a 'select all' checkbox will be put into the header. Use
+    /// [`ColumnDef::header_checkbox_selection_fn`] to provide a closure
+    /// instead.
+    header_checkbox_selection: Option<ValueOrCallback<bool>>,
 
     /// If `true`, the header checkbox selection will only select filtered
     /// items.
@@ -157,6 +493,11 @@ pub struct ColumnDef {
     // All options are enterprise-only
 
     // Rendering and Styling
+    /// A custom component used to render the cells of this column. Set with
+    /// [`ColumnDef::cell_renderer`].
+    #[field_setter(skip)]
+    cell_renderer: Option<ComponentClass>,
+
     /// Set to `true` to have the grid calculate the height of a row based on
     /// contents of this column.
     auto_height: Option<bool>,
@@ -174,16 +515,23 @@ pub struct ColumnDef {
     // TODO
 
     // Row Dragging
-    /// Set to `true` to allow row dragging.
-    row_drag: Option<bool>, // TODO: add support for callback function
+    /// Set to `true` to allow row dragging. Use [`ColumnDef::row_drag_fn`] to
+    /// decide per row instead.
+    row_drag: Option<ValueOrCallback<bool>>,
 
-    /// Set to `true` to allow dragging for native drag and drop.
-    dnd_source: Option<bool>, // TODO: add support for callback function
+    /// Set to `true` to allow dragging for native drag and drop. Use
+    /// [`ColumnDef::dnd_source_fn`] to decide per row instead.
+    dnd_source: Option<ValueOrCallback<bool>>,
     // TODO
 
     // Row Grouping
     // All options and enterprise-only
 
+    // Selection
+    /// Set to `true` to render a selection checkbox in the column. Use
+    /// [`ColumnDef::checkbox_selection_fn`] to decide per row instead.
+    checkbox_selection: Option<ValueOrCallback<bool>>,
+
     // Sort
     /// Set wether the column is sortable.
     sortable: Option<bool>,
@@ -210,14 +558,13 @@ pub struct ColumnDef {
     unsort_icon: Option<bool>,
 
     // Spanning
-    //
-    // TODO: support callback function
-    /// Set the span of the column.
-    col_span: Option<u32>,
+    /// Set the span of the column. Use [`ColumnDef::col_span_fn`] to set the
+    /// span per cell instead.
+    col_span: Option<ValueOrCallback<u32>>,
 
-    // TODO: support callback function
-    /// Set the span of the row.
-    row_span: Option<u32>,
+    /// Set the span of the row. Use [`ColumnDef::row_span_fn`] to set the span
+    /// per cell instead.
+    row_span: Option<ValueOrCallback<u32>>,
 
     // Tooltips
     /// The field of the tooltip to apply to the cell.
@@ -264,10 +611,141 @@ impl ColumnDef {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// A closure that gets the value to be displayed from your data, which is
+    /// decoded into `T`.
+    pub fn value_getter_fn<T, R, F>(mut self, getter: F) -> Self
+    where
+        T: FromJsValue + 'static,
+        R: ToJsValue + 'static,
+        F: FnMut(ValueGetterParams<T>) -> R + 'static,
+    {
+        let getter = callback::<IValueGetterParams, _, _, _>(getter);
+        self.value_getter = Some(ValueOrCallback::Callback(getter));
+        self
+    }
+
+    /// A closure to format a value. Not used for CSV export or copy to
+    /// clipboard; only for UI cell rendering.
+    pub fn value_formatter_fn<T, F>(mut self, formatter: F) -> Self
+    where
+        T: FromJsValue + 'static,
+        F: FnMut(ValueFormatterParams<T>) -> String + 'static,
+    {
+        let formatter = callback::<IValueFormatterParams, _, _, _>(formatter);
+        self.value_formatter = Some(ValueOrCallback::Callback(formatter));
+        self
+    }
+
+    /// A closure which decides whether the cell in the given row is editable.
+    pub fn editable_fn<T, F>(mut self, editable: F) -> Self
+    where
+        T: FromJsValue + 'static,
+        F: FnMut(CellCallbackParams<T>) -> bool + 'static,
+    {
+        let editable = callback::<ICellCallbackParams, _, _, _>(editable);
+        self.editable = Some(ValueOrCallback::Callback(editable));
+        self
+    }
+
+    /// A closure which decides whether the given row can be dragged.
+    pub fn row_drag_fn<T, F>(mut self, row_drag: F) -> Self
+    where
+        T: FromJsValue + 'static,
+        F: FnMut(CellCallbackParams<T>) -> bool + 'static,
+    {
+        let row_drag = callback::<ICellCallbackParams, _, _, _>(row_drag);
+        self.row_drag = Some(ValueOrCallback::Callback(row_drag));
+        self
+    }
+
+    /// A closure which decides whether the given row can be dragged for native
+    /// drag and drop.
+    pub fn dnd_source_fn<T, F>(mut self, dnd_source: F) -> Self
+    where
+        T: FromJsValue + 'static,
+        F: FnMut(CellCallbackParams<T>) -> bool + 'static,
+    {
+        let dnd_source = callback::<ICellCallbackParams, _, _, _>(dnd_source);
+        self.dnd_source = Some(ValueOrCallback::Callback(dnd_source));
+        self
+    }
+
+    /// A closure which decides whether a selection checkbox is rendered in
+    /// the given row.
+    pub fn checkbox_selection_fn<T, F>(mut self, checkbox_selection: F) -> Self
+    where
+        T: FromJsValue + 'static,
+        F: FnMut(CellCallbackParams<T>) -> bool + 'static,
+    {
+        let checkbox_selection = callback::<ICellCallbackParams, _, _, _>(checkbox_selection);
+        self.checkbox_selection = Some(ValueOrCallback::Callback(checkbox_selection));
+        self
+    }
+
+    /// A closure which returns the number of columns the cell in the given row
+    /// should span.
+    pub fn col_span_fn<T, F>(mut self, col_span: F) -> Self
+    where
+        T: FromJsValue + 'static,
+        F: FnMut(ColSpanParams<T>) -> u32 + 'static,
+    {
+        let col_span = callback::<IColSpanParams, _, _, _>(col_span);
+        self.col_span = Some(ValueOrCallback::Callback(col_span));
+        self
+    }
+
+    /// A closure which returns the number of rows the cell in the given row
+    /// should span.
+    pub fn row_span_fn<T, F>(mut self, row_span: F) -> Self
+    where
+        T: FromJsValue + 'static,
+        F: FnMut(RowSpanParams<T>) -> u32 + 'static,
+    {
+        let row_span = callback::<IRowSpanParams, _, _, _>(row_span);
+        self.row_span = Some(ValueOrCallback::Callback(row_span));
+        self
+    }
+
+    /// Set the cell editor to use for this column, either one of the editors
+    /// provided by AG Grid or a custom [`CellEditor`].
+    pub fn cell_editor(mut self, editor: CellEditorType) -> Self {
+        let params = match editor {
+            CellEditorType::AgTextCellEditor(ref params) => Some(params.to_js_value()),
+            CellEditorType::AgSelectCellEditor(ref params) => Some(params.to_js_value()),
+            CellEditorType::AgLargeTextCellEditor(ref params) => Some(params.to_js_value()),
+            CellEditorType::Custom(_) => None,
+        };
+        self.cell_editor = Some(editor);
+        self.cell_editor_params = params;
+        self
+    }
+
+    /// Render the cells of this column with a custom [`CellRenderer`]. The
+    /// `factory` is called to create a new renderer for each cell.
+    pub fn cell_renderer<R, F>(mut self, factory: F) -> Self
+    where
+        R: CellRenderer + 'static,
+        F: Fn() -> R + 'static,
+    {
+        self.cell_renderer = Some(cell_renderer_class(factory));
+        self
+    }
+
+    /// A closure which decides whether a 'select all' checkbox will be put into
+    /// the header.
+    pub fn header_checkbox_selection_fn<F>(mut self, header_checkbox_selection: F) -> Self
+    where
+        F: FnMut(HeaderCheckboxSelectionParams) -> bool + 'static,
+    {
+        let header_checkbox_selection =
+            callback::<IHeaderCheckboxSelectionParams, _, _, _>(header_checkbox_selection);
+        self.header_checkbox_selection = Some(ValueOrCallback::Callback(header_checkbox_selection));
+        self
+    }
 }
 
 /// Allowed values for [`ColumnDef::filter`][crate::ColumnDef::filter].
-#[derive(ToJsValue)]
 pub enum Filter {
     /// A filter for number comparisons.
     AgNumberColumnFilter,
@@ -278,14 +756,135 @@ pub enum Filter {
     /// A filter influenced by how filters work in Microsoft Excel. This is an
     /// AG Grid Enterprise feature.
     AgSetColumnFilter,
+    /// A filter which combines several other filters on the same column. This
+    /// is an AG Grid Enterprise feature.
+    AgMultiColumnFilter,
     /// Enable the default filter. The default is Text Filter for AG Grid
     /// Community and Set Filter for AG Grid Enterprise.
-    #[js_value(serialize_as = "true")]
     True,
     /// Explicitly disable filtering.
-    #[js_value(serialize_as = "false")]
     False,
-    // TODO: Custom(FilterComponent)
+    /// A filter component class, such as one built with [`Filter::custom`].
+    Custom(ComponentClass),
+}
+
+impl Filter {
+    /// Filter with a custom [`FilterComponent`]. The `factory` is called to
+    /// create a new filter for each column it is used on.
+    pub fn custom<C, F>(factory: F) -> Self
+    where
+        C: FilterComponent + 'static,
+        F: Fn() -> C + 'static,
+    {
+        Self::Custom(filter_component_class(factory))
+    }
+}
+
+impl ToJsValue for Filter {
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::AgNumberColumnFilter => "agNumberColumnFilter".into(),
+            Self::AgTextColumnFilter => "agTextColumnFilter".into(),
+            Self::AgDateColumnFilter => "agDateColumnFilter".into(),
+            Self::AgSetColumnFilter => "agSetColumnFilter".into(),
+            Self::AgMultiColumnFilter => "agMultiColumnFilter".into(),
+            Self::True => true.into(),
+            Self::False => false.into(),
+            Self::Custom(class) => class.to_js_value(),
+        }
+    }
+}
+
+/// Allowed values for [`ColumnDef::cell_editor`][crate::ColumnDef::cell_editor].
+pub enum CellEditorType {
+    /// A simple text editor. This is the default.
+    AgTextCellEditor(TextCellEditorParams),
+    /// A drop down list of values to choose from.
+    AgSelectCellEditor(SelectCellEditorParams),
+    /// A text area editor for editing long strings in a popup.
+    AgLargeTextCellEditor(LargeTextCellEditorParams),
+    /// A cell editor class, such as one built with [`CellEditorType::custom`].
+    Custom(ComponentClass),
+}
+
+impl CellEditorType {
+    /// Edit cells with a custom [`CellEditor`]. The `factory` is called to
+    /// create a new editor each time editing starts.
+    pub fn custom<E, F>(factory: F) -> Self
+    where
+        E: CellEditor + 'static,
+        F: Fn() -> E + 'static,
+    {
+        Self::Custom(cell_editor_class(factory))
+    }
+}
+
+impl ToJsValue for CellEditorType {
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::AgTextCellEditor(_) => "agTextCellEditor".into(),
+            Self::AgSelectCellEditor(_) => "agSelectCellEditor".into(),
+            Self::AgLargeTextCellEditor(_) => "agLargeTextCellEditor".into(),
+            Self::Custom(class) => class.to_js_value(),
+        }
+    }
+}
+
+/// Parameters for [`CellEditorType::AgTextCellEditor`].
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct TextCellEditorParams {
+    /// Set to `true` to show the value formatted by the column's value
+    /// formatter when editing starts.
+    use_formatter: Option<bool>,
+
+    /// The maximum number of characters allowed.
+    max_length: Option<u32>,
+}
+
+impl TextCellEditorParams {
+    /// Create new, empty, parameters.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Parameters for [`CellEditorType::AgSelectCellEditor`].
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct SelectCellEditorParams {
+    /// The values to choose from.
+    values: Option<Vec<String>>,
+}
+
+impl SelectCellEditorParams {
+    /// Create new parameters with the given values to choose from.
+    pub fn new<S: AsRef<str>>(values: &[S]) -> Self {
+        Self {
+            values: Some(values.iter().map(|v| v.as_ref().to_string()).collect()),
+        }
+    }
+}
+
+/// Parameters for [`CellEditorType::AgLargeTextCellEditor`].
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct LargeTextCellEditorParams {
+    /// The maximum number of characters allowed.
+    max_length: Option<u32>,
+
+    /// The number of character rows to display.
+    rows: Option<u32>,
+
+    /// The number of character columns to display.
+    cols: Option<u32>,
+}
+
+impl LargeTextCellEditorParams {
+    /// Create new, empty, parameters.
+    pub fn new() -> Self {
+        Default::default()
+    }
 }
 
 /// Allowed values for
@@ -303,7 +902,7 @@ pub enum LockPosition {
 /// Allowed values for
 /// [`ColumnDef::pinned`][crate::ColumnDef::pinned] and
 /// [`ColumnDef::initial_pinned`][crate::ColumnDef::initial_pinned].
-#[derive(ToJsValue)]
+#[derive(ToJsValue, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PinnedPosition {
     #[js_value(serialize_as = "true")]
     True,
@@ -313,6 +912,60 @@ pub enum PinnedPosition {
     Right,
 }
 
+impl PinnedPosition {
+    /// Convert the pinned string used by AG Grid, where a missing value means
+    /// the column is not pinned.
+    pub(crate) fn from_js_pinned(pinned: Option<String>) -> Self {
+        match pinned.as_deref() {
+            Some("left") => Self::Left,
+            Some("right") => Self::Right,
+            _ => Self::False,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for PinnedPosition {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::True => s.serialize_bool(true),
+            Self::False => s.serialize_bool(false),
+            Self::Left => s.serialize_str("left"),
+            Self::Right => s.serialize_str("right"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for PinnedPosition {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Pinned {
+            Bool(bool),
+            Side(String),
+        }
+
+        match Option::<Pinned>::deserialize(d)? {
+            Some(Pinned::Bool(true)) => Ok(Self::True),
+            Some(Pinned::Bool(false)) | None => Ok(Self::False),
+            Some(Pinned::Side(side)) => match side.as_str() {
+                "left" => Ok(Self::Left),
+                "right" => Ok(Self::Right),
+                other => Err(D::Error::unknown_variant(other, &["left", "right"])),
+            },
+        }
+    }
+}
+
 /// Allowed values for
 /// [`ColumnDef::cell_editor_popup_position`][crate::ColumnDef::cell_editor_popup_position].
 #[derive(ToJsValue)]
@@ -0,0 +1,394 @@
+//! Traits for implementing AG Grid components, such as cell renderers, editors
+//! and filters, in Rust.
+
+use std::{cell::RefCell, rc::Rc};
+
+use ag_grid_core::{
+    convert::{FromJsValue, FromJsValueError, ToJsValue},
+    imports::{log, ObjectExt},
+};
+use js_sys::Function;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::HtmlElement;
+
+use crate::callbacks::{
+    CellEditorParams, CellRendererParams, DoesFilterPassParams, FilterComponentParams,
+    ICellEditorParams, ICellRendererParams, IDoesFilterPassParams, IFilterComponentParams,
+};
+
+/// A custom [`cell renderer`], used to display the contents of a cell. Set it
+/// on a column with [`ColumnDef::cell_renderer`][crate::ColumnDef::cell_renderer].
+///
+/// A new instance of the renderer is created for each cell.
+///
+/// [`cell renderer`]: https://www.ag-grid.com/javascript-data-grid/component-cell-renderer/
+pub trait CellRenderer {
+    /// The type into which the row data is decoded.
+    type Row: FromJsValue;
+
+    /// Called once when the cell is created. Returns the element to display in
+    /// the cell.
+    fn init(&mut self, params: CellRendererParams<Self::Row>) -> HtmlElement;
+
+    /// Called when the cell's value has changed. Return `true` if the renderer
+    /// updated itself, or `false` to have the grid destroy it and create a new
+    /// one.
+    fn refresh(&mut self, _params: CellRendererParams<Self::Row>) -> bool {
+        false
+    }
+
+    /// Called when the cell is destroyed, to allow any clean up to take place.
+    fn destroy(&mut self) {}
+}
+
+/// Build the JavaScript class AG Grid uses to create each instance of a cell
+/// renderer.
+pub(crate) fn cell_renderer_class<R, F>(factory: F) -> ComponentClass
+where
+    R: CellRenderer + 'static,
+    F: Fn() -> R + 'static,
+{
+    component_class(move || {
+        let renderer = Rc::new(RefCell::new(factory()));
+        let gui = Rc::new(RefCell::new(JsValue::undefined()));
+        let instance = Instance::new();
+
+        instance.method("init", {
+            let renderer = renderer.clone();
+            let gui = gui.clone();
+            move |params| {
+                if let Some(params) = decode_params::<ICellRendererParams, _>(&params) {
+                    *gui.borrow_mut() = renderer.borrow_mut().init(params).into();
+                }
+                JsValue::undefined()
+            }
+        });
+        instance.method("getGui", move |_| gui.borrow().clone());
+        instance.method("refresh", {
+            let renderer = renderer.clone();
+            move |params| match decode_params::<ICellRendererParams, _>(&params) {
+                Some(params) => renderer.borrow_mut().refresh(params).into(),
+                None => false.into(),
+            }
+        });
+
+        instance.finish(move || renderer.borrow_mut().destroy())
+    })
+}
+
+/// A custom [`cell editor`], used to edit the contents of a cell. Set it on a
+/// column with [`ColumnDef::cell_editor`][crate::ColumnDef::cell_editor] and
+/// [`CellEditorType::custom`][crate::column::CellEditorType::custom].
+///
+/// A new instance of the editor is created each time editing starts.
+///
+/// [`cell editor`]: https://www.ag-grid.com/javascript-data-grid/component-cell-editor/
+pub trait CellEditor {
+    /// The type into which the row data is decoded.
+    type Row: FromJsValue;
+
+    /// The type of the value returned once editing has finished.
+    type Value: ToJsValue;
+
+    /// Called once when editing starts, before the editor is attached to the
+    /// DOM.
+    fn init(&mut self, params: CellEditorParams<Self::Row>);
+
+    /// Returns the element to display while editing.
+    fn get_gui(&self) -> HtmlElement;
+
+    /// Returns the final value to the grid once editing has finished.
+    fn get_value(&self) -> Self::Value;
+
+    /// Called once the editor has been attached to the DOM, e.g. to focus an
+    /// input.
+    fn after_gui_attached(&mut self) {}
+
+    /// Return `true` to display the editor in a popup rather than inside the
+    /// cell.
+    fn is_popup(&self) -> bool {
+        false
+    }
+
+    /// Called once before editing starts. Return `true` to cancel editing, e.g.
+    /// if the key which started the edit is not suitable for this editor.
+    fn is_cancel_before_start(&self) -> bool {
+        false
+    }
+
+    /// Called once after editing has finished. Return `true` to discard the
+    /// edited value.
+    fn is_cancel_after_end(&self) -> bool {
+        false
+    }
+
+    /// Called when the editor is destroyed, to allow any clean up to take
+    /// place.
+    fn destroy(&mut self) {}
+}
+
+/// Build the JavaScript class AG Grid uses to create each instance of a cell
+/// editor.
+pub(crate) fn cell_editor_class<E, F>(factory: F) -> ComponentClass
+where
+    E: CellEditor + 'static,
+    F: Fn() -> E + 'static,
+{
+    component_class(move || {
+        let editor = Rc::new(RefCell::new(factory()));
+        let instance = Instance::new();
+
+        instance.method("init", {
+            let editor = editor.clone();
+            move |params| {
+                if let Some(params) = decode_params::<ICellEditorParams, _>(&params) {
+                    editor.borrow_mut().init(params);
+                }
+                JsValue::undefined()
+            }
+        });
+        instance.method("getGui", {
+            let editor = editor.clone();
+            move |_| editor.borrow().get_gui().into()
+        });
+        instance.method("getValue", {
+            let editor = editor.clone();
+            move |_| editor.borrow().get_value().to_js_value()
+        });
+        instance.method("afterGuiAttached", {
+            let editor = editor.clone();
+            move |_| {
+                editor.borrow_mut().after_gui_attached();
+                JsValue::undefined()
+            }
+        });
+        instance.method("isPopup", {
+            let editor = editor.clone();
+            move |_| editor.borrow().is_popup().into()
+        });
+        instance.method("isCancelBeforeStart", {
+            let editor = editor.clone();
+            move |_| editor.borrow().is_cancel_before_start().into()
+        });
+        instance.method("isCancelAfterEnd", {
+            let editor = editor.clone();
+            move |_| editor.borrow().is_cancel_after_end().into()
+        });
+
+        instance.finish(move || editor.borrow_mut().destroy())
+    })
+}
+
+/// A custom [`filter component`]. Set it on a column with
+/// [`ColumnDef::filter`][crate::ColumnDef::filter] and
+/// [`Filter::custom`][crate::column::Filter::custom].
+///
+/// When using a server-side row model, the models of custom filters are passed
+/// to the datasource as
+/// [`FilterModelType::Custom`][crate::filter::FilterModelType::Custom].
+///
+/// [`filter component`]: https://www.ag-grid.com/javascript-data-grid/component-filter/
+pub trait FilterComponent {
+    /// The type into which the row data is decoded.
+    type Row: FromJsValue;
+
+    /// The type describing the state of the filter.
+    type Model: ToJsValue + FromJsValue;
+
+    /// Called once when the filter is created.
+    fn init(&mut self, params: FilterComponentParams);
+
+    /// Returns the element to display in the filter menu.
+    fn get_gui(&self) -> HtmlElement;
+
+    /// Return `true` if the given row passes the filter. Only called when the
+    /// filter is active.
+    fn does_filter_pass(&self, params: DoesFilterPassParams<Self::Row>) -> bool;
+
+    /// Return `true` if the filter is currently filtering rows.
+    fn is_filter_active(&self) -> bool;
+
+    /// Returns the state of the filter, or `None` if it is not active.
+    fn get_model(&self) -> Option<Self::Model>;
+
+    /// Restore the state of the filter. `None` resets the filter.
+    fn set_model(&mut self, model: Option<Self::Model>);
+
+    /// Called when the filter is destroyed, to allow any clean up to take
+    /// place.
+    fn destroy(&mut self) {}
+}
+
+/// Build the JavaScript class AG Grid uses to create each instance of a filter
+/// component.
+pub(crate) fn filter_component_class<C, F>(factory: F) -> ComponentClass
+where
+    C: FilterComponent + 'static,
+    F: Fn() -> C + 'static,
+{
+    component_class(move || {
+        let filter = Rc::new(RefCell::new(factory()));
+        let instance = Instance::new();
+
+        instance.method("init", {
+            let filter = filter.clone();
+            move |params| {
+                let params = params.unchecked_ref::<IFilterComponentParams>().into();
+                filter.borrow_mut().init(params);
+                JsValue::undefined()
+            }
+        });
+        instance.method("getGui", {
+            let filter = filter.clone();
+            move |_| filter.borrow().get_gui().into()
+        });
+        instance.method("doesFilterPass", {
+            let filter = filter.clone();
+            move |params| match decode_params::<IDoesFilterPassParams, _>(&params) {
+                Some(params) => filter.borrow().does_filter_pass(params).into(),
+                None => false.into(),
+            }
+        });
+        instance.method("isFilterActive", {
+            let filter = filter.clone();
+            move |_| filter.borrow().is_filter_active().into()
+        });
+        instance.method("getModel", {
+            let filter = filter.clone();
+            move |_| filter.borrow().get_model().to_js_value()
+        });
+        instance.method("setModel", {
+            let filter = filter.clone();
+            move |model| {
+                // The grid passes `null` to reset the filter, which decodes to `None`.
+                match FromJsValue::from_js_value(&model) {
+                    Ok(model) => filter.borrow_mut().set_model(model),
+                    Err(e) => log(&format!("Error decoding filter model: {e}")),
+                }
+                JsValue::undefined()
+            }
+        });
+
+        instance.finish(move || filter.borrow_mut().destroy())
+    })
+}
+
+/// Decode the parameters passed to a component method. If the row data cannot
+/// be decoded, the error is logged and `None` is returned.
+fn decode_params<I, P>(params: &JsValue) -> Option<P>
+where
+    I: JsCast,
+    P: for<'a> TryFrom<&'a I, Error = FromJsValueError>,
+{
+    P::try_from(params.unchecked_ref::<I>())
+        .map_err(|e| log(&format!("Error decoding component params: {e}")))
+        .ok()
+}
+
+/// A JavaScript component class, which AG Grid instantiates with `new` for
+/// each cell or column that uses it.
+///
+/// A class built from a Rust component, e.g. with
+/// [`Filter::custom`][crate::column::Filter::custom], owns the closure which
+/// creates each instance. The closure is freed when the class is dropped, so
+/// the class must outlive the grid. Any other JavaScript class can be used by
+/// converting it with `ComponentClass::from`.
+pub struct ComponentClass {
+    class: Function,
+    _factory: Option<Closure<dyn FnMut() -> JsValue>>,
+}
+
+impl From<Function> for ComponentClass {
+    fn from(class: Function) -> Self {
+        Self {
+            class,
+            _factory: None,
+        }
+    }
+}
+
+impl ToJsValue for ComponentClass {
+    fn to_js_value(&self) -> JsValue {
+        self.class.clone().into()
+    }
+}
+
+// Rust closures cannot be called with `new`, so wrap the factory in a plain
+// function; returning an object from a constructor makes `new` evaluate to that
+// object. AG Grid only treats a function as a class component if `getGui` is
+// present on its prototype.
+#[wasm_bindgen(inline_js = "
+export function wrapFactory(factory) {
+    function Component() { return factory(); }
+    Component.prototype.getGui = function() { return null; };
+    return Component;
+}
+")]
+extern "C" {
+    #[wasm_bindgen(js_name = wrapFactory)]
+    fn wrap_factory(factory: &Function) -> Function;
+}
+
+/// Build a JavaScript class which AG Grid can instantiate with `new`. Each
+/// instance is the object returned by `factory`.
+fn component_class<F>(mut factory: F) -> ComponentClass
+where
+    F: FnMut() -> ObjectExt + 'static,
+{
+    let factory = Closure::<dyn FnMut() -> JsValue>::new(move || factory().into());
+    let class = wrap_factory(factory.as_ref().unchecked_ref());
+
+    ComponentClass {
+        class,
+        _factory: Some(factory),
+    }
+}
+
+type Method = Closure<dyn FnMut(JsValue) -> JsValue>;
+
+/// A component instance under construction.
+///
+/// The closures backing its methods are owned by the instance, rather than
+/// leaked, and are dropped once the grid destroys it.
+struct Instance {
+    obj: ObjectExt,
+    methods: Rc<RefCell<Vec<Method>>>,
+}
+
+impl Instance {
+    fn new() -> Self {
+        Self {
+            obj: ObjectExt::new(),
+            methods: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    /// Set a method on the instance.
+    fn method<F>(&self, name: &str, f: F)
+    where
+        F: FnMut(JsValue) -> JsValue + 'static,
+    {
+        let method = Method::new(f);
+        self.obj.set(name, method.as_ref().clone());
+        self.methods.borrow_mut().push(method);
+    }
+
+    /// Set the `destroy` method, which calls `destroy` and then drops every
+    /// method of the instance, and return the finished instance.
+    fn finish<F>(self, mut destroy: F) -> ObjectExt
+    where
+        F: FnMut() + 'static,
+    {
+        let methods = self.methods.clone();
+        self.method("destroy", move |_| {
+            destroy();
+            // The grid calls no other method once an instance is destroyed. The
+            // closure running now is only freed once it returns.
+            let methods = std::mem::take(&mut *methods.borrow_mut());
+            drop(methods);
+            JsValue::undefined()
+        });
+
+        self.obj
+    }
+}
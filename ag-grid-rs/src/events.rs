@@ -0,0 +1,370 @@
+//! Types pertaining to grid events.
+
+use ag_grid_core::{
+    convert::{FromJsValue, FromJsValueError},
+    imports::log,
+};
+use ag_grid_derive::{FromInterface, ToJsValue};
+use js_sys::Array;
+use wasm_bindgen::{prelude::*, JsCast};
+
+use crate::{column::Column, ColumnApi, GridApi};
+
+/// The events which can be listened to with
+/// [`GridApi::add_event_listener`][crate::GridApi::add_event_listener].
+#[derive(ToJsValue, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventType {
+    CellClicked,
+    CellValueChanged,
+    RowSelected,
+    SelectionChanged,
+    SortChanged,
+    FilterChanged,
+    ColumnResized,
+    ColumnMoved,
+    GridReady,
+}
+
+/// A typed grid event which can be listened to with
+/// [`GridApi::add_event_listener`][crate::GridApi::add_event_listener].
+pub trait GridEvent: Sized {
+    /// The type of event which produces this struct.
+    const EVENT_TYPE: EventType;
+
+    #[doc(hidden)]
+    fn from_event(event: &JsValue) -> Result<Self, FromJsValueError>;
+}
+
+macro_rules! grid_event {
+    ($event:ident<T>, $interface:ident, $event_type:ident) => {
+        impl<T> GridEvent for $event<T>
+        where
+            T: FromJsValue,
+        {
+            const EVENT_TYPE: EventType = EventType::$event_type;
+
+            fn from_event(event: &JsValue) -> Result<Self, FromJsValueError> {
+                event.unchecked_ref::<$interface>().try_into()
+            }
+        }
+    };
+    ($event:ident, $interface:ident, $event_type:ident) => {
+        impl GridEvent for $event {
+            const EVENT_TYPE: EventType = EventType::$event_type;
+
+            fn from_event(event: &JsValue) -> Result<Self, FromJsValueError> {
+                Ok(event.unchecked_ref::<$interface>().into())
+            }
+        }
+    };
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ICellClickedEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &ICellClickedEvent) -> Column;
+
+    #[wasm_bindgen(method, getter, js_name = rowIndex)]
+    fn row_index(this: &ICellClickedEvent) -> Option<u32>;
+
+    #[wasm_bindgen(method, getter)]
+    fn value(this: &ICellClickedEvent) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &ICellClickedEvent) -> JsValue;
+}
+
+/// Fired when a cell is clicked.
+///
+/// The row data is decoded into `T`, which defaults to the raw
+/// `wasm_bindgen::JsValue`.
+#[derive(Debug, FromInterface)]
+pub struct CellClickedEvent<T = JsValue> {
+    /// The column of the clicked cell.
+    pub column: Column,
+    /// The index of the row of the clicked cell.
+    pub row_index: Option<u32>,
+    /// The value of the clicked cell.
+    pub value: JsValue,
+    /// The data of the row of the clicked cell.
+    pub data: Option<T>,
+}
+
+grid_event!(CellClickedEvent<T>, ICellClickedEvent, CellClicked);
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ICellValueChangedEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &ICellValueChangedEvent) -> Column;
+
+    #[wasm_bindgen(method, getter, js_name = rowIndex)]
+    fn row_index(this: &ICellValueChangedEvent) -> Option<u32>;
+
+    #[wasm_bindgen(method, getter, js_name = oldValue)]
+    fn old_value(this: &ICellValueChangedEvent) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = newValue)]
+    fn new_value(this: &ICellValueChangedEvent) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &ICellValueChangedEvent) -> JsValue;
+}
+
+/// Fired when a cell's value has been changed, either by the user editing it
+/// or through the API.
+///
+/// The row data is decoded into `T`, which defaults to the raw
+/// `wasm_bindgen::JsValue`.
+#[derive(Debug, FromInterface)]
+pub struct CellValueChangedEvent<T = JsValue> {
+    /// The column of the changed cell.
+    pub column: Column,
+    /// The index of the row of the changed cell.
+    pub row_index: Option<u32>,
+    /// The value of the cell before the change.
+    pub old_value: JsValue,
+    /// The value of the cell after the change.
+    pub new_value: JsValue,
+    /// The data of the row of the changed cell, after the change.
+    pub data: Option<T>,
+}
+
+grid_event!(
+    CellValueChangedEvent<T>,
+    ICellValueChangedEvent,
+    CellValueChanged
+);
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IRowSelectedEvent;
+
+    #[wasm_bindgen(method, getter, js_name = rowIndex)]
+    fn row_index(this: &IRowSelectedEvent) -> Option<u32>;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &IRowSelectedEvent) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn node(this: &IRowSelectedEvent) -> IRowSelectedNode;
+
+    type IRowSelectedNode;
+
+    #[wasm_bindgen(method, js_name = isSelected)]
+    fn is_selected(this: &IRowSelectedNode) -> Option<bool>;
+}
+
+impl IRowSelectedEvent {
+    fn selected(&self) -> bool {
+        self.node().is_selected().unwrap_or_default()
+    }
+}
+
+/// Fired when a row is selected or deselected.
+///
+/// The row data is decoded into `T`, which defaults to the raw
+/// `wasm_bindgen::JsValue`.
+#[derive(Debug, FromInterface)]
+pub struct RowSelectedEvent<T = JsValue> {
+    /// The index of the row.
+    pub row_index: Option<u32>,
+    /// The data of the row.
+    pub data: Option<T>,
+    /// Whether the row is now selected.
+    pub selected: bool,
+}
+
+grid_event!(RowSelectedEvent<T>, IRowSelectedEvent, RowSelected);
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ISelectionChangedEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn source(this: &ISelectionChangedEvent) -> Option<String>;
+}
+
+/// Fired when the set of selected rows changes.
+#[derive(Debug, FromInterface)]
+pub struct SelectionChangedEvent {
+    /// What caused the selection to change, e.g. `"checkboxSelected"`.
+    pub source: Option<String>,
+}
+
+grid_event!(
+    SelectionChangedEvent,
+    ISelectionChangedEvent,
+    SelectionChanged
+);
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ISortChangedEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn source(this: &ISortChangedEvent) -> Option<String>;
+}
+
+/// Fired when the sort applied to the grid changes.
+#[derive(Debug, FromInterface)]
+pub struct SortChangedEvent {
+    /// What caused the sort to change, e.g. `"uiColumnSorted"`.
+    pub source: Option<String>,
+}
+
+grid_event!(SortChangedEvent, ISortChangedEvent, SortChanged);
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IFilterChangedEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn columns(this: &IFilterChangedEvent) -> Array;
+}
+
+/// Fired when the filters applied to the grid change.
+#[derive(Debug, FromInterface)]
+pub struct FilterChangedEvent {
+    /// The columns whose filters changed.
+    pub columns: Vec<Column>,
+}
+
+grid_event!(FilterChangedEvent, IFilterChangedEvent, FilterChanged);
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IColumnResizedEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &IColumnResizedEvent) -> Option<Column>;
+
+    #[wasm_bindgen(method, getter)]
+    fn finished(this: &IColumnResizedEvent) -> bool;
+}
+
+/// Fired when a column is resized. While the user is dragging the column edge
+/// this is fired repeatedly, with `finished` set to `true` for the final
+/// event.
+#[derive(Debug, FromInterface)]
+pub struct ColumnResizedEvent {
+    /// The resized column, if a single column was resized.
+    pub column: Option<Column>,
+    /// Whether the resize has finished.
+    pub finished: bool,
+}
+
+grid_event!(ColumnResizedEvent, IColumnResizedEvent, ColumnResized);
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IColumnMovedEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn column(this: &IColumnMovedEvent) -> Option<Column>;
+
+    #[wasm_bindgen(method, getter, js_name = toIndex)]
+    fn to_index(this: &IColumnMovedEvent) -> Option<u32>;
+}
+
+/// Fired when a column is moved.
+#[derive(Debug, FromInterface)]
+pub struct ColumnMovedEvent {
+    /// The moved column, if a single column was moved.
+    pub column: Option<Column>,
+    /// The index the column was moved to.
+    pub to_index: Option<u32>,
+}
+
+grid_event!(ColumnMovedEvent, IColumnMovedEvent, ColumnMoved);
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IGridReadyEvent;
+
+    #[wasm_bindgen(method, getter)]
+    fn api(this: &IGridReadyEvent) -> GridApi;
+
+    #[wasm_bindgen(method, getter, js_name = columnApi)]
+    fn column_api(this: &IGridReadyEvent) -> ColumnApi;
+}
+
+/// Fired once the grid has initialised and is ready to use.
+#[derive(Debug, FromInterface)]
+pub struct GridReadyEvent {
+    /// A handle for the AG Grid Grid API.
+    pub api: GridApi,
+    /// A handle for the AG Grid Column API.
+    pub column_api: ColumnApi,
+}
+
+grid_event!(GridReadyEvent, IGridReadyEvent, GridReady);
+
+/// Wrap a closure taking a typed event into a [`Closure`]. If the event cannot
+/// be decoded, e.g. because its row data does not match `T`, the error is
+/// logged and the handler is not called.
+pub(crate) fn event_closure<E, F>(mut handler: F) -> Closure<dyn FnMut(JsValue)>
+where
+    E: GridEvent + 'static,
+    F: FnMut(E) + 'static,
+{
+    Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| match E::from_event(&event) {
+        Ok(event) => handler(event),
+        Err(e) => log(&format!("Error decoding {:?} event: {e}", E::EVENT_TYPE)),
+    })
+}
+
+/// A guard for a listener added with
+/// [`GridApi::add_event_listener`][crate::GridApi::add_event_listener]. The
+/// listener is removed from the grid when the guard is dropped.
+#[must_use = "the event listener is removed when the guard is dropped"]
+pub struct EventListener {
+    api: GridApi,
+    event_type: EventType,
+    callback: Option<Closure<dyn FnMut(JsValue)>>,
+}
+
+impl EventListener {
+    pub(crate) fn new(
+        api: GridApi,
+        event_type: EventType,
+        callback: Closure<dyn FnMut(JsValue)>,
+    ) -> Self {
+        Self {
+            api,
+            event_type,
+            callback: Some(callback),
+        }
+    }
+
+    /// The type of event being listened to.
+    pub fn event_type(&self) -> EventType {
+        self.event_type
+    }
+
+    /// Keep the listener registered for the lifetime of the grid, rather than
+    /// removing it when the guard is dropped.
+    pub fn forget(mut self) {
+        if let Some(callback) = self.callback.take() {
+            callback.forget();
+        }
+    }
+
+    /// Take the callback out of the guard, so that dropping it no longer
+    /// removes the listener.
+    pub(crate) fn take_callback(&mut self) -> Option<Closure<dyn FnMut(JsValue)>> {
+        self.callback.take()
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        if let Some(callback) = self.callback.take() {
+            self.api
+                .remove_js_event_listener(self.event_type, callback.as_ref().unchecked_ref());
+        }
+    }
+}
@@ -1,12 +1,55 @@
 //! Types pertaining to grid filtering.
 
-use ag_grid_core::imports::ObjectExt;
-use chrono::NaiveDateTime;
-use wasm_bindgen::JsCast;
+use std::{cmp::Ordering, collections::HashMap, error::Error, fmt, future::Future};
 
+use ag_grid_core::{convert::ToJsValue, imports::ObjectExt};
+use ag_grid_derive::{FieldSetter, ToJsValue};
+use chrono::{NaiveDate, NaiveDateTime};
+use js_sys::{Array, Date, Object};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::{
+    callbacks::{Callback, ISetFilterValuesParams, SetFilterValuesParams},
+    column::Filter,
+    types::ValueOrCallback,
+};
+
+/// The format in which AG Grid represents dates in a filter model.
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// The error returned when a filter model received from AG Grid could not be
+/// parsed.
 #[derive(Debug)]
+pub struct FilterParseError {
+    message: String,
+}
+
+impl FilterParseError {
+    fn new<T: Into<String>>(message: T) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FilterParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse filter model: {}", self.message)
+    }
+}
+
+impl Error for FilterParseError {}
+
+#[derive(Debug, Clone, ToJsValue)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum Comparator {
     Equals,
+    #[js_value(rename = "notEqual")]
+    #[cfg_attr(feature = "serde", serde(rename = "notEqual"))]
     NotEquals,
     Contains,
     NotContains,
@@ -19,12 +62,16 @@ pub enum Comparator {
     InRange,
     Blank,
     NotBlank,
+    #[js_value(rename = "empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "empty"))]
     ChooseOne,
 }
 
-impl From<String> for Comparator {
-    fn from(v: String) -> Self {
-        match v.as_str() {
+impl TryFrom<String> for Comparator {
+    type Error = FilterParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        let comparator = match v.as_str() {
             "equals" => Self::Equals,
             "notEqual" => Self::NotEquals,
             "contains" => Self::Contains,
@@ -39,169 +86,877 @@ impl From<String> for Comparator {
             "blank" => Self::Blank,
             "notBlank" => Self::NotBlank,
             "empty" => Self::ChooseOne,
-            // This is the full set of options from the AG Grid library.
-            _ => unreachable!(),
+            _ => {
+                return Err(FilterParseError::new(format!(
+                    "unknown filter option `{v}`"
+                )))
+            }
+        };
+        Ok(comparator)
+    }
+}
+
+/// Allowed values for
+/// [`ColumnDef::filter_params`][crate::ColumnDef::filter_params]. The variant
+/// should match the filter set with
+/// [`ColumnDef::filter`][crate::ColumnDef::filter].
+pub enum FilterParams {
+    /// Parameters for [`Filter::AgTextColumnFilter`].
+    Text(TextFilterParams),
+    /// Parameters for [`Filter::AgNumberColumnFilter`].
+    Number(NumberFilterParams),
+    /// Parameters for [`Filter::AgDateColumnFilter`].
+    Date(DateFilterParams),
+    /// Parameters for [`Filter::AgSetColumnFilter`].
+    Set(SetFilterParams),
+    /// Parameters for [`Filter::AgMultiColumnFilter`].
+    Multi(MultiFilterParams),
+}
+
+impl ToJsValue for FilterParams {
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::Text(params) => params.to_js_value(),
+            Self::Number(params) => params.to_js_value(),
+            Self::Date(params) => params.to_js_value(),
+            Self::Set(params) => params.to_js_value(),
+            Self::Multi(params) => params.to_js_value(),
         }
     }
 }
 
-#[derive(Debug)]
+/// The buttons which can be shown at the bottom of a filter.
+#[derive(ToJsValue)]
+pub enum FilterButton {
+    /// Apply the filter. When present, the filter is only applied once this is
+    /// clicked.
+    Apply,
+    /// Clear the filter's inputs, without removing the active filter.
+    Clear,
+    /// Clear the filter's inputs and remove the active filter.
+    Reset,
+    /// Discard any changes made since the filter was last applied, and close
+    /// the popup.
+    Cancel,
+}
+
+/// Parameters for the provided text filter.
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct TextFilterParams {
+    /// The filter options to show, in order.
+    filter_options: Option<Vec<Comparator>>,
+
+    /// The default filter option to be selected.
+    default_option: Option<Comparator>,
+
+    /// The maximum number of conditions the user can combine.
+    max_num_conditions: Option<u32>,
+
+    /// The number of milliseconds to wait after the user stops typing before
+    /// applying the filter.
+    debounce_ms: Option<u32>,
+
+    /// The buttons to show at the bottom of the filter.
+    buttons: Option<Vec<FilterButton>>,
+
+    /// Set to `true` to make text filtering case-sensitive.
+    case_sensitive: Option<bool>,
+}
+
+impl TextFilterParams {
+    /// Create new, empty, parameters.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Parameters for the provided number filter.
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct NumberFilterParams {
+    /// The filter options to show, in order.
+    filter_options: Option<Vec<Comparator>>,
+
+    /// The default filter option to be selected.
+    default_option: Option<Comparator>,
+
+    /// The maximum number of conditions the user can combine.
+    max_num_conditions: Option<u32>,
+
+    /// The number of milliseconds to wait after the user stops typing before
+    /// applying the filter.
+    debounce_ms: Option<u32>,
+
+    /// The buttons to show at the bottom of the filter.
+    buttons: Option<Vec<FilterButton>>,
+
+    /// Set to `true` to include the bounds of an `InRange` filter.
+    in_range_inclusive: Option<bool>,
+}
+
+impl NumberFilterParams {
+    /// Create new, empty, parameters.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Parameters for the provided date filter.
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct DateFilterParams {
+    /// The filter options to show, in order.
+    filter_options: Option<Vec<Comparator>>,
+
+    /// The default filter option to be selected.
+    default_option: Option<Comparator>,
+
+    /// The maximum number of conditions the user can combine.
+    max_num_conditions: Option<u32>,
+
+    /// The number of milliseconds to wait after the user stops typing before
+    /// applying the filter.
+    debounce_ms: Option<u32>,
+
+    /// The buttons to show at the bottom of the filter.
+    buttons: Option<Vec<FilterButton>>,
+
+    /// Set to `true` to include the bounds of an `InRange` filter.
+    in_range_inclusive: Option<bool>,
+
+    /// Compare the date chosen in the filter with a cell value. Set with
+    /// [`DateFilterParams::comparator`].
+    #[field_setter(skip)]
+    comparator: Option<Closure<dyn FnMut(Date, JsValue) -> f64>>,
+}
+
+impl DateFilterParams {
+    /// Create new, empty, parameters.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A closure which compares the date chosen in the filter, at midnight
+    /// local time, with the value of a cell. Return how the cell value orders
+    /// relative to the filter date. This is required if the cell values are
+    /// not JavaScript `Date` objects.
+    pub fn comparator<F>(mut self, mut comparator: F) -> Self
+    where
+        F: FnMut(NaiveDateTime, JsValue) -> Ordering + 'static,
+    {
+        let comparator = Closure::<dyn FnMut(Date, JsValue) -> f64>::new(
+            move |filter_date: Date, cell_value: JsValue| match from_js_date(&filter_date) {
+                Some(filter_date) => comparator(filter_date, cell_value) as i32 as f64,
+                // `NaN` is neither less than, greater than nor equal to zero,
+                // so the cell does not match.
+                None => f64::NAN,
+            },
+        );
+        self.comparator = Some(comparator);
+        self
+    }
+}
+
+/// Parameters for the set filter. This is an AG Grid Enterprise feature.
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct SetFilterParams {
+    /// The values to show in the filter list. Use
+    /// [`SetFilterParams::values_fn`] to load the values asynchronously
+    /// instead. If not set, the values are taken from the row data.
+    values: Option<ValueOrCallback<Vec<Option<String>>>>,
+
+    /// Set to `true` to hide the mini filter, used to search the list of
+    /// values.
+    suppress_mini_filter: Option<bool>,
+
+    /// Set to `true` to hide the 'Select All' checkbox.
+    suppress_select_all: Option<bool>,
+}
+
+impl SetFilterParams {
+    /// Create new, empty, parameters.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// An async closure which loads the values to show in the filter list,
+    /// e.g. from the server. It is called when the filter is first opened.
+    pub fn values_fn<F, Fut>(mut self, mut values: F) -> Self
+    where
+        F: FnMut(SetFilterValuesParams) -> Fut + 'static,
+        Fut: Future<Output = Vec<Option<String>>> + 'static,
+    {
+        let values = Callback::new(move |js_params: JsValue| {
+            let js_params = js_params.unchecked_into::<ISetFilterValuesParams>();
+            let fut = values((&js_params).into());
+            spawn_local(async move {
+                let values = fut.await.to_js_value();
+                js_params
+                    .success()
+                    .call1(&JsValue::null(), &values)
+                    .expect("failed calling success callback");
+            });
+            JsValue::undefined()
+        });
+        self.values = Some(ValueOrCallback::Callback(values));
+        self
+    }
+}
+
+/// Parameters for the multi filter, which combines several child filters on one
+/// column. This is an AG Grid Enterprise feature.
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct MultiFilterParams {
+    /// The child filters to show, in order.
+    filters: Option<Vec<MultiFilterDef>>,
+
+    /// Set to `true` to hide the buttons of the child filters.
+    hide_child_filter_buttons: Option<bool>,
+}
+
+impl MultiFilterParams {
+    /// Create new parameters with the given child filters.
+    pub fn new(filters: Vec<MultiFilterDef>) -> Self {
+        Self {
+            filters: Some(filters),
+            ..Default::default()
+        }
+    }
+}
+
+/// A child filter of the multi filter.
+#[derive(FieldSetter, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct MultiFilterDef {
+    /// The filter to use.
+    filter: Option<Filter>,
+
+    /// Parameters for the filter.
+    filter_params: Option<FilterParams>,
+
+    /// How the filter is displayed within the multi filter.
+    display: Option<MultiFilterDisplay>,
+
+    /// The title of the filter, used when it is displayed in a sub-menu or an
+    /// accordion.
+    title: Option<String>,
+}
+
+impl MultiFilterDef {
+    /// Create a new child filter definition.
+    pub fn new(filter: Filter) -> Self {
+        Self {
+            filter: Some(filter),
+            ..Default::default()
+        }
+    }
+}
+
+/// Allowed values for [`MultiFilterDef::display`].
+#[derive(ToJsValue)]
+pub enum MultiFilterDisplay {
+    /// Show the filter inline, one after another. This is the default.
+    Inline,
+    /// Show the filter in a sub-menu.
+    SubMenu,
+    /// Show the filter in an expandable accordion.
+    Accordion,
+}
+
+/// Convert a JavaScript `Date` into a `NaiveDateTime` in local time. Returns
+/// `None` for an `Invalid Date`.
+fn from_js_date(date: &Date) -> Option<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(
+        date.get_full_year() as i32,
+        date.get_month() + 1,
+        date.get_date(),
+    )
+    .and_then(|d| d.and_hms_opt(date.get_hours(), date.get_minutes(), date.get_seconds()))
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum FilterModelType {
-    Single(FilterModel),
+    // When deserializing, `Combined` must be tried first because a combined
+    // model would otherwise also match an empty `Single` model.
     Combined(CombinedFilterModel),
+    Single(FilterModel),
+    /// The model of a custom filter component, as returned by
+    /// [`FilterComponent::get_model`][crate::components::FilterComponent::get_model].
+    ///
+    /// A `JsValue` has no serde representation, so with the `serde` feature,
+    /// serializing a filter model which contains a custom model is an error.
+    /// Remove any custom models before persisting the filter model, and
+    /// persist them separately, e.g. by decoding them into the component's
+    /// `Model` type.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(JsValue),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "filterType", rename_all = "lowercase"))]
 pub enum FilterModel {
     Text(TextFilter),
     Number(NumberFilter),
     Date(DateFilter),
+    Set(SetFilter),
+    Multi(MultiFilter),
+}
+
+impl ToJsValue for FilterModelType {
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::Combined(model) => model.to_js_value(),
+            Self::Single(model) => model.to_js_value(),
+            Self::Custom(model) => model.clone(),
+        }
+    }
+}
+
+/// Parse the filter model of the whole grid, as found in
+/// [`GetRowsParams`][crate::callbacks::GetRowsParams] or returned from
+/// [`GridApi::get_filter_model`][crate::GridApi::get_filter_model].
+pub(crate) fn filter_model_from_object(
+    obj: &ObjectExt,
+) -> Result<HashMap<String, FilterModelType>, FilterParseError> {
+    obj.entries()
+        .into_iter()
+        .map(|(col, filter)| Ok((col, filter.try_into()?)))
+        .collect()
 }
 
-impl FilterModel {
-    pub fn from_object(obj: &ObjectExt) -> Self {
-        let filter_type = obj.get_string_unchecked("filterType");
+impl TryFrom<JsValue> for FilterModelType {
+    type Error = FilterParseError;
+
+    fn try_from(filter: JsValue) -> Result<Self, Self::Error> {
+        let obj = filter.unchecked_ref::<ObjectExt>();
+        let filter_type = if filter.is_object() {
+            obj.get_string("filterType")
+        } else {
+            None
+        };
+
+        let filter = match filter_type.as_deref() {
+            Some("text" | "number" | "date") if Object::has_own(obj, &"operator".into()) => {
+                FilterModelType::Combined(obj.try_into()?)
+            }
+            Some("text" | "number" | "date" | "set" | "multi") => {
+                FilterModelType::Single(obj.try_into()?)
+            }
+            // Any other model comes from a custom filter component.
+            _ => FilterModelType::Custom(filter),
+        };
+
+        Ok(filter)
+    }
+}
+
+/// Add the `filterType` tag to a serialized filter model.
+fn with_filter_type(model: JsValue, filter_type: &str) -> JsValue {
+    model
+        .unchecked_ref::<ObjectExt>()
+        .set("filterType", filter_type.into());
+    model
+}
+
+impl ToJsValue for FilterModel {
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::Text(filter) => with_filter_type(filter.to_js_value(), "text"),
+            Self::Number(filter) => with_filter_type(filter.to_js_value(), "number"),
+            Self::Date(filter) => with_filter_type(filter.to_js_value(), "date"),
+            Self::Set(filter) => with_filter_type(filter.to_js_value(), "set"),
+            Self::Multi(filter) => with_filter_type(filter.to_js_value(), "multi"),
+        }
+    }
+}
+
+impl TryFrom<&ObjectExt> for FilterModel {
+    type Error = FilterParseError;
+
+    fn try_from(obj: &ObjectExt) -> Result<Self, Self::Error> {
+        let filter_type = required_string(obj, "filterType")?;
         match filter_type.as_str() {
-            "text" => FilterModel::Text(TextFilter::from_object(obj)),
-            "number" => FilterModel::Number(NumberFilter::from_object(obj)),
-            "date" => FilterModel::Date(DateFilter::from_object(obj)),
-            _ => unreachable!(),
+            "text" => Ok(FilterModel::Text(obj.try_into()?)),
+            "number" => Ok(FilterModel::Number(obj.try_into()?)),
+            "date" => Ok(FilterModel::Date(obj.try_into()?)),
+            "set" => Ok(FilterModel::Set(obj.try_into()?)),
+            "multi" => Ok(FilterModel::Multi(obj.try_into()?)),
+            _ => Err(FilterParseError::new(format!(
+                "unknown filter type `{filter_type}`"
+            ))),
         }
     }
 }
 
-impl CombinedFilterModel {
-    pub fn from_object(obj: &ObjectExt) -> Self {
-        let filter_type = obj.get_string_unchecked("filterType");
-        let operator = obj.get_string_unchecked("operator").into();
-        let c1 = obj.get("condition1").unchecked_into::<ObjectExt>();
-        let c2 = obj.get("condition2").unchecked_into::<ObjectExt>();
+impl TryFrom<&ObjectExt> for CombinedFilterModel {
+    type Error = FilterParseError;
+
+    fn try_from(obj: &ObjectExt) -> Result<Self, Self::Error> {
+        let filter_type = required_string(obj, "filterType")?;
+        let operator = required_string(obj, "operator")?.try_into()?;
         match filter_type.as_str() {
-            "text" => CombinedFilterModel::Text(CombinedTextFilter {
+            "text" => Ok(CombinedFilterModel::Text(CombinedTextFilter {
                 operator,
-                condition_1: TextFilter::from_object(&c1),
-                condition_2: TextFilter::from_object(&c2),
-            }),
-            "number" => CombinedFilterModel::Number(CombinedNumberFilter {
+                conditions: conditions_from_object(obj)?,
+            })),
+            "number" => Ok(CombinedFilterModel::Number(CombinedNumberFilter {
                 operator,
-                condition_1: NumberFilter::from_object(&c1),
-                condition_2: NumberFilter::from_object(&c2),
-            }),
-            "date" => CombinedFilterModel::Date(CombinedDateFilter {
+                conditions: conditions_from_object(obj)?,
+            })),
+            "date" => Ok(CombinedFilterModel::Date(CombinedDateFilter {
                 operator,
-                condition_1: DateFilter::from_object(&c1),
-                condition_2: DateFilter::from_object(&c2),
-            }),
-
-            _ => unreachable!(),
+                conditions: conditions_from_object(obj)?,
+            })),
+            _ => Err(FilterParseError::new(format!(
+                "unknown filter type `{filter_type}`"
+            ))),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "filterType", rename_all = "lowercase"))]
 pub enum CombinedFilterModel {
     Text(CombinedTextFilter),
     Number(CombinedNumberFilter),
     Date(CombinedDateFilter),
 }
 
+impl ToJsValue for CombinedFilterModel {
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::Text(filter) => with_filter_type(filter.to_js_value(), "text"),
+            Self::Number(filter) => with_filter_type(filter.to_js_value(), "number"),
+            Self::Date(filter) => with_filter_type(filter.to_js_value(), "date"),
+        }
+    }
+}
+
 /// Describe how to handle multiple conditions.
-#[derive(Debug)]
+#[derive(Debug, Clone, ToJsValue)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum JoinOperator {
     /// Combine two given conditions using *and* semantics.
+    #[js_value(rename = "AND")]
     And,
     /// Combine two given conditions using *or* semantics.
+    #[js_value(rename = "OR")]
     Or,
 }
 
-impl From<String> for JoinOperator {
-    fn from(v: String) -> Self {
+impl TryFrom<String> for JoinOperator {
+    type Error = FilterParseError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
         match v.as_str() {
-            "AND" => Self::And,
-            "OR" => Self::Or,
-            // This is the complete list of operators as per AG Grid.
-            _ => unreachable!(),
+            "AND" => Ok(Self::And),
+            "OR" => Ok(Self::Or),
+            _ => Err(FilterParseError::new(format!(
+                "unknown join operator `{v}`"
+            ))),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, ToJsValue)]
+#[js_value(skip_serializing_none)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TextFilter {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub filter: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub filter_to: Option<String>,
+    #[js_value(rename = "type")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "type", skip_serializing_if = "Option::is_none")
+    )]
     pub comparator: Option<Comparator>,
 }
 
-impl TextFilter {
-    pub fn from_object(obj: &ObjectExt) -> Self {
-        let comparator = obj.get_string("type").map(Comparator::from);
-        Self {
+impl TryFrom<&ObjectExt> for TextFilter {
+    type Error = FilterParseError;
+
+    fn try_from(obj: &ObjectExt) -> Result<Self, Self::Error> {
+        Ok(Self {
             filter: obj.get_string("filter"),
             filter_to: obj.get_string("filterTo"),
-            comparator,
-        }
+            comparator: comparator_from_object(obj)?,
+        })
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, ToJsValue)]
+#[js_value(skip_serializing_none)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct NumberFilter {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub filter: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub filter_to: Option<f64>,
+    #[js_value(rename = "type")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "type", skip_serializing_if = "Option::is_none")
+    )]
     pub comparator: Option<Comparator>,
 }
 
-impl NumberFilter {
-    pub fn from_object(obj: &ObjectExt) -> Self {
-        let comparator = obj.get_string("type").map(Comparator::from);
-        Self {
+impl TryFrom<&ObjectExt> for NumberFilter {
+    type Error = FilterParseError;
+
+    fn try_from(obj: &ObjectExt) -> Result<Self, Self::Error> {
+        Ok(Self {
             filter: obj.get_f64("filter"),
             filter_to: obj.get_f64("filterTo"),
-            comparator,
-        }
+            comparator: comparator_from_object(obj)?,
+        })
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DateFilter {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "dateFrom",
+            default,
+            with = "date_format",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub filter: Option<NaiveDateTime>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "dateTo",
+            default,
+            with = "date_format",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub filter_to: Option<NaiveDateTime>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "type", skip_serializing_if = "Option::is_none")
+    )]
     pub comparator: Option<Comparator>,
 }
 
-impl DateFilter {
-    pub fn from_object(obj: &ObjectExt) -> Self {
-        let comparator = obj.get_string("type").map(Comparator::from);
-        Self {
-            filter: obj.get_string("dateFrom").map(|dt| {
-                NaiveDateTime::parse_from_str(&dt, "%Y-%m-%d %H:%M:%S")
-                    .expect("Ag Grid should always pass a date in format 'YYYY-MM-DD hh:mm:ss'")
-            }),
-            filter_to: obj.get_string("dateFrom").map(|dt| {
-                NaiveDateTime::parse_from_str(&dt, "%Y-%m-%d %H:%M:%S")
-                    .expect("Ag Grid should always pass a date in format 'YYYY-MM-DD hh:mm:ss'")
-            }),
-            comparator,
+impl ToJsValue for DateFilter {
+    fn to_js_value(&self) -> JsValue {
+        let obj = ObjectExt::new();
+        if let Some(date) = &self.filter {
+            obj.set("dateFrom", date.format(DATE_FORMAT).to_string().into());
+        }
+        if let Some(date) = &self.filter_to {
+            obj.set("dateTo", date.format(DATE_FORMAT).to_string().into());
         }
+        if let Some(comparator) = &self.comparator {
+            obj.set("type", comparator.to_js_value());
+        }
+        obj.into()
     }
 }
 
-#[derive(Debug)]
+impl TryFrom<&ObjectExt> for DateFilter {
+    type Error = FilterParseError;
+
+    fn try_from(obj: &ObjectExt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            filter: date_from_object(obj, "dateFrom")?,
+            filter_to: date_from_object(obj, "dateTo")?,
+            comparator: comparator_from_object(obj)?,
+        })
+    }
+}
+
+/// The model of the set filter. This is an AG Grid Enterprise feature.
+#[derive(Debug, Clone, ToJsValue)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetFilter {
+    /// The selected values. `None` represents blank cells.
+    pub values: Vec<Option<String>>,
+}
+
+impl TryFrom<&ObjectExt> for SetFilter {
+    type Error = FilterParseError;
+
+    fn try_from(obj: &ObjectExt) -> Result<Self, Self::Error> {
+        let values = obj.get("values");
+        if !Array::is_array(&values) {
+            return Err(FilterParseError::new("missing `values`"));
+        }
+
+        let values = values
+            .unchecked_into::<Array>()
+            .iter()
+            .map(|value| match value.as_string() {
+                Some(value) => Ok(Some(value)),
+                None if value.is_null() => Ok(None),
+                None => Err(FilterParseError::new(format!(
+                    "invalid set filter value {value:?}"
+                ))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { values })
+    }
+}
+
+/// The model of the multi filter. This is an AG Grid Enterprise feature.
+#[derive(Debug, Clone, ToJsValue)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct MultiFilter {
+    /// The models of the child filters, in the order they were given in
+    /// [`MultiFilterParams`]. Inactive child filters are `None`.
+    ///
+    /// Child filters may have combined conditions, so these are
+    /// [`FilterModelType`]s rather than [`FilterModel`]s.
+    pub filter_models: Vec<Option<FilterModelType>>,
+}
+
+impl TryFrom<&ObjectExt> for MultiFilter {
+    type Error = FilterParseError;
+
+    fn try_from(obj: &ObjectExt) -> Result<Self, Self::Error> {
+        let filter_models = obj.get("filterModels");
+        if !Array::is_array(&filter_models) {
+            return Err(FilterParseError::new("missing `filterModels`"));
+        }
+
+        let filter_models = filter_models
+            .unchecked_into::<Array>()
+            .iter()
+            .map(|model| {
+                if model.is_null() || model.is_undefined() {
+                    Ok(None)
+                } else {
+                    model.try_into().map(Some)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { filter_models })
+    }
+}
+
+/// Read the optional filter option, stored under `type`, of a filter model.
+fn comparator_from_object(obj: &ObjectExt) -> Result<Option<Comparator>, FilterParseError> {
+    obj.get_string("type").map(Comparator::try_from).transpose()
+}
+
+/// Read an optional date, in AG Grid's `YYYY-MM-DD hh:mm:ss` format, from a
+/// filter model.
+fn date_from_object(obj: &ObjectExt, key: &str) -> Result<Option<NaiveDateTime>, FilterParseError> {
+    obj.get_string(key)
+        .map(|date| {
+            NaiveDateTime::parse_from_str(&date, DATE_FORMAT)
+                .map_err(|e| FilterParseError::new(format!("invalid `{key}` date `{date}`: {e}")))
+        })
+        .transpose()
+}
+
+/// Read a string which must be present in a filter model.
+fn required_string(obj: &ObjectExt, key: &str) -> Result<String, FilterParseError> {
+    obj.get_string(key)
+        .ok_or_else(|| FilterParseError::new(format!("missing `{key}`")))
+}
+
+/// A filter model combining multiple text filter conditions.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "CombinedFilterRepr<TextFilter>",
+        into = "CombinedFilterRepr<TextFilter>"
+    )
+)]
 pub struct CombinedTextFilter {
-    pub condition_1: TextFilter,
-    pub condition_2: TextFilter,
+    pub conditions: Vec<TextFilter>,
     pub operator: JoinOperator,
 }
 
-#[derive(Debug)]
+/// A filter model combining multiple number filter conditions.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "CombinedFilterRepr<NumberFilter>",
+        into = "CombinedFilterRepr<NumberFilter>"
+    )
+)]
 pub struct CombinedNumberFilter {
-    pub condition_1: NumberFilter,
-    pub condition_2: NumberFilter,
+    pub conditions: Vec<NumberFilter>,
     pub operator: JoinOperator,
 }
 
-#[derive(Debug)]
+/// A filter model combining multiple date filter conditions.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "CombinedFilterRepr<DateFilter>",
+        into = "CombinedFilterRepr<DateFilter>"
+    )
+)]
 pub struct CombinedDateFilter {
-    pub condition_1: DateFilter,
-    pub condition_2: DateFilter,
+    pub conditions: Vec<DateFilter>,
     pub operator: JoinOperator,
 }
+
+macro_rules! combined_filter {
+    ($combined:ident, $filter:ident) => {
+        impl ToJsValue for $combined {
+            fn to_js_value(&self) -> JsValue {
+                combined_to_js_value(&self.operator, &self.conditions)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl From<CombinedFilterRepr<$filter>> for $combined {
+            fn from(repr: CombinedFilterRepr<$filter>) -> Self {
+                let (operator, conditions) = repr.into_parts();
+                Self {
+                    conditions,
+                    operator,
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl From<$combined> for CombinedFilterRepr<$filter> {
+            fn from(filter: $combined) -> Self {
+                CombinedFilterRepr::new(filter.operator, filter.conditions)
+            }
+        }
+    };
+}
+
+combined_filter!(CombinedTextFilter, TextFilter);
+combined_filter!(CombinedNumberFilter, NumberFilter);
+combined_filter!(CombinedDateFilter, DateFilter);
+
+/// Read the conditions of a combined filter model. AG Grid 29 and later send a
+/// `conditions` array, whereas earlier versions send exactly two conditions as
+/// `condition1` and `condition2`.
+fn conditions_from_object<T>(obj: &ObjectExt) -> Result<Vec<T>, FilterParseError>
+where
+    T: for<'a> TryFrom<&'a ObjectExt, Error = FilterParseError>,
+{
+    let conditions = obj.get("conditions");
+    if Array::is_array(&conditions) {
+        conditions
+            .unchecked_into::<Array>()
+            .iter()
+            .map(|condition| condition.unchecked_ref::<ObjectExt>().try_into())
+            .collect()
+    } else {
+        ["condition1", "condition2"]
+            .into_iter()
+            .map(|key| obj.get(key))
+            .filter(JsValue::is_object)
+            .map(|condition| condition.unchecked_ref::<ObjectExt>().try_into())
+            .collect()
+    }
+}
+
+/// Serialize a combined filter model in both the current and legacy shapes,
+/// so that it is understood by any version of AG Grid. Legacy versions only
+/// see the first two conditions.
+fn combined_to_js_value<T: ToJsValue>(operator: &JoinOperator, conditions: &[T]) -> JsValue {
+    let obj = ObjectExt::new();
+    obj.set(
+        "conditions",
+        conditions
+            .iter()
+            .map(ToJsValue::to_js_value)
+            .collect::<Array>()
+            .into(),
+    );
+    for (key, condition) in ["condition1", "condition2"].into_iter().zip(conditions) {
+        obj.set(key, condition.to_js_value());
+    }
+    obj.set("operator", operator.to_js_value());
+    obj.into()
+}
+
+/// The serde representation of a combined filter model, mirroring
+/// [`combined_to_js_value`].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+struct CombinedFilterRepr<T> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    conditions: Option<Vec<T>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    condition1: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    condition2: Option<T>,
+    operator: JoinOperator,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Clone> CombinedFilterRepr<T> {
+    fn new(operator: JoinOperator, conditions: Vec<T>) -> Self {
+        let mut legacy = conditions.iter().cloned();
+        Self {
+            condition1: legacy.next(),
+            condition2: legacy.next(),
+            conditions: Some(conditions),
+            operator,
+        }
+    }
+
+    fn into_parts(self) -> (JoinOperator, Vec<T>) {
+        let conditions = match self.conditions {
+            Some(conditions) => conditions,
+            None => self.condition1.into_iter().chain(self.condition2).collect(),
+        };
+        (self.operator, conditions)
+    }
+}
+
+/// (De)serialize dates in the same format that AG Grid uses in its filter
+/// models.
+#[cfg(feature = "serde")]
+mod date_format {
+    use chrono::NaiveDateTime;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use super::DATE_FORMAT;
+
+    pub(super) fn serialize<S>(date: &Option<NaiveDateTime>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(date) => s.serialize_some(&date.format(DATE_FORMAT).to_string()),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D>(d: D) -> Result<Option<NaiveDateTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(d)?
+            .map(|date| NaiveDateTime::parse_from_str(&date, DATE_FORMAT).map_err(D::Error::custom))
+            .transpose()
+    }
+}
@@ -1,12 +1,26 @@
 //! Types pertaining to the `Grid` itself.
 
-use ag_grid_core::convert::ToJsValue;
-use wasm_bindgen::prelude::*;
+use std::{collections::HashMap, future::Future};
+
+use ag_grid_core::convert::{FromJsValue, FromJsValueError, ToJsValue};
+use js_sys::{Function, Object, Promise};
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::JsFuture;
 use web_sys::HtmlElement;
 
-use crate::{column::ColumnApi, gridoptions::DataSource};
+use crate::{
+    callbacks::Callback,
+    column::{ColumnApi, ColumnDef},
+    events::{event_closure, EventListener, EventType, GridEvent},
+    filter::{filter_model_from_object, FilterModelType, FilterParseError},
+    gridoptions::DataSource,
+    row::{IRowNode, RowDataTransaction, RowNode, RowNodeTransaction},
+};
 
 /// A handle to the underlying JavaScript grid.
+///
+/// The handle owns the column definitions the grid was built with, including
+/// any custom components. Keep it alive for as long as the grid is in use.
 pub struct Grid {
     // /// The [`GridOptions`] struct used to construct the grid.
     // pub grid_options: GridOptions<T>,
@@ -18,6 +32,12 @@ pub struct Grid {
     ///
     /// [`Column API`]: https://www.ag-grid.com/javascript-data-grid/column-api/
     pub column_api: ColumnApi,
+    // The Rust closures behind any custom components are owned by the column
+    // definitions, so they must live as long as the grid.
+    pub(crate) _column_defs: Vec<ColumnDef>,
+    // Likewise for the event handlers and callbacks set on the grid options.
+    pub(crate) _event_handlers: Vec<Closure<dyn FnMut(JsValue)>>,
+    pub(crate) _callbacks: Vec<Callback>,
 }
 
 #[wasm_bindgen]
@@ -46,6 +66,7 @@ extern "C" {
     /// A handle for the AG Grid [`Grid API`].
     ///
     /// [`Grid API`]: https://www.ag-grid.com/javascript-data-grid/grid-api/
+    #[derive(Debug, Clone)]
     pub type GridApi;
 
     #[wasm_bindgen(method)]
@@ -54,8 +75,59 @@ extern "C" {
     #[wasm_bindgen(method)]
     fn setRowData(this: &GridApi, data: JsValue);
 
+    #[wasm_bindgen(method)]
+    fn applyTransaction(this: &GridApi, transaction: JsValue) -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn applyTransactionAsync(this: &GridApi, transaction: JsValue, callback: &Function);
+
+    #[wasm_bindgen(method)]
+    fn flushAsyncTransactions(this: &GridApi);
+
+    #[wasm_bindgen(method)]
+    fn getSelectedRows(this: &GridApi) -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn getSelectedNodes(this: &GridApi) -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn selectAll(this: &GridApi);
+
+    #[wasm_bindgen(method)]
+    fn deselectAll(this: &GridApi);
+
+    #[wasm_bindgen(method)]
+    fn selectAllFiltered(this: &GridApi);
+
+    #[wasm_bindgen(method)]
+    fn getRowNode(this: &GridApi, id: &str) -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn forEachNode(this: &GridApi, callback: &mut dyn FnMut(JsValue));
+
+    #[wasm_bindgen(method)]
+    fn forEachNodeAfterFilterAndSort(this: &GridApi, callback: &mut dyn FnMut(JsValue));
+
+    #[wasm_bindgen(method)]
+    fn getDisplayedRowAtIndex(this: &GridApi, index: u32) -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn onRowHeightChanged(this: &GridApi);
+
     #[wasm_bindgen(method)]
     fn setDatasource(this: &GridApi, data_source: DataSource);
+
+    #[wasm_bindgen(method)]
+    fn setFilterModel(this: &GridApi, model: JsValue);
+
+    #[wasm_bindgen(method)]
+    fn getFilterModel(this: &GridApi) -> Object;
+
+    #[wasm_bindgen(method)]
+    fn addEventListener(this: &GridApi, event_type: JsValue, listener: &Function);
+
+    #[wasm_bindgen(method)]
+    fn removeEventListener(this: &GridApi, event_type: JsValue, listener: &Function);
 }
 
 impl GridApi {
@@ -66,6 +138,12 @@ impl GridApi {
 
     /// Set the row data. Applicable when using
     /// [`RowModelType::ClientSide`][crate::gridoptions::RowModelType::ClientSide].
+    ///
+    /// From AG Grid 29, if
+    /// [`GridOptions::get_row_id`][crate::GridOptions::get_row_id] is set, the
+    /// grid compares the new rows with its existing rows by ID and applies
+    /// only the differences, keeping the selection and scroll position.
+    /// Earlier versions replace all of the rows.
     pub fn set_row_data<T>(&self, row_data: Vec<T>)
     where
         T: ToJsValue,
@@ -73,9 +151,193 @@ impl GridApi {
         Self::setRowData(self, row_data.to_js_value())
     }
 
+    /// Add, update and remove rows without replacing all of the row data,
+    /// which keeps the grid's scroll position and selection. Applicable when
+    /// using
+    /// [`RowModelType::ClientSide`][crate::gridoptions::RowModelType::ClientSide].
+    ///
+    /// Returns the affected rows, or `None` if the grid is using a different
+    /// row model. An error is returned if the grid's result could not be
+    /// decoded.
+    pub fn apply_transaction<T>(
+        &self,
+        transaction: RowDataTransaction<T>,
+    ) -> Result<Option<RowNodeTransaction<T>>, FromJsValueError>
+    where
+        T: ToJsValue + FromJsValue,
+    {
+        let result = Self::applyTransaction(self, transaction.to_js_value());
+        FromJsValue::from_js_value(&result)
+    }
+
+    /// Apply a transaction as part of a batch, which the grid executes all at
+    /// once after
+    /// [`GridOptions::async_transaction_wait_millis`][crate::GridOptions::async_transaction_wait_millis].
+    /// This is more efficient than [`GridApi::apply_transaction`] when
+    /// applying many transactions in quick succession.
+    ///
+    /// The transaction is queued immediately. The returned future resolves
+    /// with the affected rows once the batch has been executed, and need not
+    /// be awaited. It never resolves if the grid is not using
+    /// [`RowModelType::ClientSide`][crate::gridoptions::RowModelType::ClientSide].
+    /// An error is returned if the grid threw while queueing the transaction,
+    /// or if its result could not be decoded.
+    pub fn apply_transaction_async<T>(
+        &self,
+        transaction: RowDataTransaction<T>,
+    ) -> impl Future<Output = Result<RowNodeTransaction<T>, FromJsValueError>>
+    where
+        T: ToJsValue + FromJsValue,
+    {
+        let transaction = transaction.to_js_value();
+        // The promise is only rejected if `applyTransactionAsync` throws.
+        let promise = Promise::new(&mut |resolve, _reject| {
+            Self::applyTransactionAsync(self, transaction.clone(), &resolve)
+        });
+
+        async move {
+            let result = JsFuture::from(promise).await.map_err(|e| {
+                FromJsValueError::new(format!("failed to apply transaction: {e:?}"))
+            })?;
+            FromJsValue::from_js_value(&result)
+        }
+    }
+
+    /// Execute any transactions applied with
+    /// [`GridApi::apply_transaction_async`] which are waiting to be batched.
+    pub fn flush_async_transactions(&self) {
+        Self::flushAsyncTransactions(self)
+    }
+
+    /// Get the data of the selected rows. An error is returned if the data of
+    /// a row could not be decoded as `T`.
+    pub fn get_selected_rows<T>(&self) -> Result<Vec<T>, FromJsValueError>
+    where
+        T: FromJsValue,
+    {
+        FromJsValue::from_js_value(&Self::getSelectedRows(self))
+    }
+
+    /// Get the selected rows.
+    pub fn get_selected_nodes<T>(&self) -> Result<Vec<RowNode<T>>, FromJsValueError>
+    where
+        T: FromJsValue,
+    {
+        FromJsValue::from_js_value(&Self::getSelectedNodes(self))
+    }
+
+    /// Select all rows, regardless of filtering.
+    pub fn select_all(&self) {
+        Self::selectAll(self)
+    }
+
+    /// Clear the selection of all rows, regardless of filtering.
+    pub fn deselect_all(&self) {
+        Self::deselectAll(self)
+    }
+
+    /// Select all rows which pass the current filters.
+    pub fn select_all_filtered(&self) {
+        Self::selectAllFiltered(self)
+    }
+
+    /// Select the given row. If `clear_selection` is `true`, any other selected
+    /// rows are deselected.
+    pub fn select_node<T>(&self, node: &RowNode<T>, clear_selection: bool) {
+        node.node().set_selected(true, clear_selection)
+    }
+
+    /// Get the row with the given ID, if it exists.
+    pub fn get_row_node<T>(&self, id: &str) -> Option<RowNode<T>> {
+        let node = Self::getRowNode(self, id);
+        node.is_object()
+            .then(|| node.unchecked_into::<IRowNode>().into())
+    }
+
+    /// Call `callback` with every row in the grid, ignoring filtering and
+    /// sorting.
+    pub fn for_each_node<T, F>(&self, mut callback: F)
+    where
+        F: FnMut(RowNode<T>),
+    {
+        Self::forEachNode(self, &mut |node| {
+            callback(node.unchecked_into::<IRowNode>().into())
+        })
+    }
+
+    /// Call `callback` with every row which passes the current filters, in the
+    /// order in which they are sorted.
+    pub fn for_each_node_after_filter_and_sort<T, F>(&self, mut callback: F)
+    where
+        F: FnMut(RowNode<T>),
+    {
+        Self::forEachNodeAfterFilterAndSort(self, &mut |node| {
+            callback(node.unchecked_into::<IRowNode>().into())
+        })
+    }
+
+    /// Get the row displayed at the given index, if any.
+    pub fn get_displayed_row_at_index<T>(&self, index: u32) -> Option<RowNode<T>> {
+        let node = Self::getDisplayedRowAtIndex(self, index);
+        node.is_object()
+            .then(|| node.unchecked_into::<IRowNode>().into())
+    }
+
+    /// Lay out the rows again after their heights have been changed with
+    /// [`RowNode::set_row_height`].
+    pub fn on_row_height_changed(&self) {
+        Self::onRowHeightChanged(self)
+    }
+
     /// Set a new datasource. Applicable when using
     /// [`RowModelType::Infinite`][crate::gridoptions::RowModelType::Infinite].
     pub fn set_data_source(&self, data_source: DataSource) {
         Self::setDatasource(self, data_source)
     }
+
+    /// Set the filter model of the grid, keyed by column ID. Any column not
+    /// present in `model` has its filter cleared.
+    pub fn set_filter_model(&self, model: HashMap<String, FilterModelType>) {
+        Self::setFilterModel(self, model.to_js_value())
+    }
+
+    /// Get the current filter model of the grid, keyed by column ID.
+    pub fn get_filter_model(&self) -> Result<HashMap<String, FilterModelType>, FilterParseError> {
+        filter_model_from_object(Self::getFilterModel(self).unchecked_ref())
+    }
+
+    /// Add a listener for the event type `E`, e.g.
+    /// [`CellClickedEvent`][crate::events::CellClickedEvent].
+    ///
+    /// To remove the listener, pass the returned [`EventListener`] to
+    /// [`GridApi::remove_event_listener`], or simply drop it. Use
+    /// [`EventListener::forget`] to keep the listener for the lifetime of the
+    /// grid instead.
+    pub fn add_event_listener<E, F>(&self, listener: F) -> EventListener
+    where
+        E: GridEvent + 'static,
+        F: FnMut(E) + 'static,
+    {
+        let callback = event_closure(listener);
+        Self::addEventListener(
+            self,
+            E::EVENT_TYPE.to_js_value(),
+            callback.as_ref().unchecked_ref(),
+        );
+
+        EventListener::new(self.clone(), E::EVENT_TYPE, callback)
+    }
+
+    /// Remove a listener previously added with
+    /// [`GridApi::add_event_listener`]. Dropping the [`EventListener`] has the
+    /// same effect, but removes the listener from the grid it was added to.
+    pub fn remove_event_listener(&self, mut listener: EventListener) {
+        if let Some(callback) = listener.take_callback() {
+            self.remove_js_event_listener(listener.event_type(), callback.as_ref().unchecked_ref());
+        }
+    }
+
+    pub(crate) fn remove_js_event_listener(&self, event_type: EventType, listener: &Function) {
+        Self::removeEventListener(self, event_type.to_js_value(), listener)
+    }
 }
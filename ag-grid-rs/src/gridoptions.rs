@@ -11,10 +11,18 @@ use web_sys::HtmlElement;
 
 pub use crate::shared::SortMethod;
 use crate::{
-    callbacks::{GetRowsParams, IGetRowsParams},
+    callbacks::{
+        callback, Callback, GetRowIdParams, GetRowsParams, IGetRowIdParams, IGetRowsParams,
+    },
     column::ColumnDef,
-    convert::ToJsValue,
+    convert::{FromJsValue, ToJsValue},
+    events::{
+        event_closure, CellClickedEvent, CellValueChangedEvent, ColumnMovedEvent,
+        ColumnResizedEvent, FilterChangedEvent, GridReadyEvent, RowSelectedEvent,
+        SelectionChangedEvent, SortChangedEvent,
+    },
     grid::AgGrid,
+    row::{IRowNode, RowNode},
     types::OneOrMany,
     Grid, ToJsValue as ToJsValueMacro,
 };
@@ -305,12 +313,22 @@ where
     // RowModel
     /// Sets the row model type.
     row_model_type: Option<RowModelType>,
-    // get_row_id
+
+    /// Provide a pure function that returns a string ID to uniquely identify a
+    /// given row. This enables the grid to work optimally with data changes and
+    /// updates.
+    #[field_setter(skip)]
+    get_row_id: Option<Callback>,
 
     // RowModel: Client Side
     /// Set the row data.
     row_data: Option<Vec<T>>,
 
+    /// How many milliseconds to wait before executing a batch of transactions
+    /// applied with
+    /// [`GridApi::apply_transaction_async`][crate::GridApi::apply_transaction_async].
+    async_transaction_wait_millis: Option<u32>,
+
     // RowModel: Infinite
     datasource: Option<DataSource>,
     /// How many extra blank rows to display to the user at the end of the
@@ -403,7 +421,10 @@ where
     /// Set to `true` to allow multiple rows to be selected using single click.
     row_multi_select_with_click: Option<bool>,
 
-    // is_row_selectable
+    /// Decide whether the given row can be selected.
+    #[field_setter(skip)]
+    is_row_selectable: Option<Callback>,
+
     /// If `true`, rows will not be deselected if you hold down `Ctrl` and click
     /// the row or press `Space`.
     suppress_row_deselection: Option<bool>,
@@ -492,6 +513,44 @@ where
     /// Set to `true` to have tooltips follow the cursor once they are
     /// displayed.
     tooltip_mouse_track: Option<bool>,
+
+    // Events
+    /// Called when a cell is clicked.
+    #[field_setter(skip)]
+    on_cell_clicked: Option<Closure<dyn FnMut(JsValue)>>,
+
+    /// Called when a cell's value has been changed, either by the user
+    /// editing it or through the API.
+    #[field_setter(skip)]
+    on_cell_value_changed: Option<Closure<dyn FnMut(JsValue)>>,
+
+    /// Called when a row is selected or deselected.
+    #[field_setter(skip)]
+    on_row_selected: Option<Closure<dyn FnMut(JsValue)>>,
+
+    /// Called when the set of selected rows changes.
+    #[field_setter(skip)]
+    on_selection_changed: Option<Closure<dyn FnMut(JsValue)>>,
+
+    /// Called when the sort applied to the grid changes.
+    #[field_setter(skip)]
+    on_sort_changed: Option<Closure<dyn FnMut(JsValue)>>,
+
+    /// Called when the filters applied to the grid change.
+    #[field_setter(skip)]
+    on_filter_changed: Option<Closure<dyn FnMut(JsValue)>>,
+
+    /// Called when a column is resized.
+    #[field_setter(skip)]
+    on_column_resized: Option<Closure<dyn FnMut(JsValue)>>,
+
+    /// Called when a column is moved.
+    #[field_setter(skip)]
+    on_column_moved: Option<Closure<dyn FnMut(JsValue)>>,
+
+    /// Called when the grid has initialised and is ready to use.
+    #[field_setter(skip)]
+    on_grid_ready: Option<Closure<dyn FnMut(JsValue)>>,
 }
 
 impl<T> GridOptions<T>
@@ -505,7 +564,7 @@ where
     /// A finaliser method for the [`GridOptions`] struct. This method
     /// constructs the underlying JavaScript grid and returns a handle,
     /// [`Grid`], which provides access to the grid APIs.
-    pub fn build(self, div: HtmlElement) -> Grid {
+    pub fn build(mut self, div: HtmlElement) -> Grid {
         let grid_options = self.to_js_value();
 
         let js_grid = AgGrid::new(div, grid_options);
@@ -513,8 +572,156 @@ where
         Grid {
             api: js_grid.gridOptions().api(),
             column_api: js_grid.gridOptions().columnApi(),
+            _column_defs: self
+                .column_defs
+                .take()
+                .into_iter()
+                .flatten()
+                .chain(self.default_col_def.take())
+                .collect(),
+            _event_handlers: [
+                self.on_cell_clicked.take(),
+                self.on_cell_value_changed.take(),
+                self.on_row_selected.take(),
+                self.on_selection_changed.take(),
+                self.on_sort_changed.take(),
+                self.on_filter_changed.take(),
+                self.on_column_resized.take(),
+                self.on_column_moved.take(),
+                self.on_grid_ready.take(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            _callbacks: [self.get_row_id.take(), self.is_row_selectable.take()]
+                .into_iter()
+                .flatten()
+                .collect(),
         }
     }
+
+    /// Set a callback for when the set of selected rows changes.
+    pub fn on_selection_changed<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(SelectionChangedEvent) + 'static,
+    {
+        self.on_selection_changed = Some(event_closure(handler));
+        self
+    }
+
+    /// Set a callback for when the sort applied to the grid changes.
+    pub fn on_sort_changed<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(SortChangedEvent) + 'static,
+    {
+        self.on_sort_changed = Some(event_closure(handler));
+        self
+    }
+
+    /// Set a callback for when the filters applied to the grid change.
+    pub fn on_filter_changed<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(FilterChangedEvent) + 'static,
+    {
+        self.on_filter_changed = Some(event_closure(handler));
+        self
+    }
+
+    /// Set a callback for when a column is resized.
+    pub fn on_column_resized<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(ColumnResizedEvent) + 'static,
+    {
+        self.on_column_resized = Some(event_closure(handler));
+        self
+    }
+
+    /// Set a callback for when a column is moved.
+    pub fn on_column_moved<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(ColumnMovedEvent) + 'static,
+    {
+        self.on_column_moved = Some(event_closure(handler));
+        self
+    }
+
+    /// Set a callback for when the grid has initialised and is ready to use.
+    pub fn on_grid_ready<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(GridReadyEvent) + 'static,
+    {
+        self.on_grid_ready = Some(event_closure(handler));
+        self
+    }
+}
+
+impl<T> GridOptions<T>
+where
+    T: ToJsValue + FromJsValue + 'static,
+{
+    /// Provide a function which returns a string ID to uniquely identify a
+    /// given row. Row IDs are required to update and remove rows with
+    /// [`GridApi::apply_transaction`][crate::GridApi::apply_transaction], and
+    /// keep rows, along with their selection, stable when the row data
+    /// changes.
+    ///
+    /// From AG Grid 29, when this is set,
+    /// [`GridApi::set_row_data`][crate::GridApi::set_row_data] performs a
+    /// delta update: rows whose ID is already in the grid are updated in
+    /// place, rather than all rows being replaced.
+    ///
+    /// Types implementing [`RowId`][crate::row::RowId] can pass its method
+    /// directly, e.g. `.get_row_id(MyRow::row_id)`.
+    pub fn get_row_id<F>(mut self, get_row_id: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        self.get_row_id = Some(callback::<IGetRowIdParams, _, _, _>(
+            move |params: GetRowIdParams<T>| get_row_id(&params.data),
+        ));
+        self
+    }
+
+    /// Provide a closure which decides whether the given row can be selected.
+    /// Rows which cannot be selected have their selection checkbox disabled,
+    /// or hidden unless
+    /// [`ColumnDef::show_disabled_checkboxes`][crate::ColumnDef::show_disabled_checkboxes]
+    /// is set.
+    pub fn is_row_selectable<F>(mut self, is_row_selectable: F) -> Self
+    where
+        F: FnMut(RowNode<T>) -> bool + 'static,
+    {
+        self.is_row_selectable = Some(callback::<IRowNode, _, _, _>(is_row_selectable));
+        self
+    }
+
+    /// Set a callback for when a cell is clicked.
+    pub fn on_cell_clicked<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(CellClickedEvent<T>) + 'static,
+    {
+        self.on_cell_clicked = Some(event_closure(handler));
+        self
+    }
+
+    /// Set a callback for when a cell's value has been changed, either by the
+    /// user editing it or through the API.
+    pub fn on_cell_value_changed<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(CellValueChangedEvent<T>) + 'static,
+    {
+        self.on_cell_value_changed = Some(event_closure(handler));
+        self
+    }
+
+    /// Set a callback for when a row is selected or deselected.
+    pub fn on_row_selected<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(RowSelectedEvent<T>) + 'static,
+    {
+        self.on_row_selected = Some(event_closure(handler));
+        self
+    }
 }
 
 /// Allowed values for [`GridOptions::multi_sort_key`].
@@ -590,7 +797,17 @@ impl DataSourceBuilder {
     {
         let get_rows =
             Closure::<dyn FnMut(IGetRowsParams)>::new(move |js_params: IGetRowsParams| {
-                let params = (&js_params).into();
+                let params = match GetRowsParams::try_from(&js_params) {
+                    Ok(params) => params,
+                    Err(e) => {
+                        log(&format!("Error parsing get_rows params: {e}"));
+                        js_params
+                            .fail_callback()
+                            .call0(&JsValue::null())
+                            .expect("failed calling failure callback");
+                        return;
+                    }
+                };
                 let fut = get_rows(params);
 
                 let wrapper = async move {
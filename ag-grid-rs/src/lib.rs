@@ -6,7 +6,7 @@
 //! A simple example demonstrating server-side data fetching using `Yew` and
 //! related dependencies is as follows:
 //!
-//! ```rust
+//! ```rust,no_run
 //! use ag_grid_rs::{
 //!     gridoptions::{DataSourceBuilder, RowModelType},
 //!     ColumnDef, GridOptions, ToJsValue,
@@ -53,8 +53,9 @@
 //!                 .datasource(data_source)
 //!                 .build(grid_div);
 //!
-//!             // `grid` now provides a handle to the grid and column APIs
-//!             || ()
+//!             // `grid` now provides a handle to the grid and column APIs. It owns the
+//!             // column definitions, so keep it until the component is unmounted
+//!             move || drop(grid)
 //!         },
 //!         (),
 //!     );
@@ -88,20 +89,29 @@
 //!
 //! [`AG Grid`]: https://www.ag-grid.com/javascript-data-grid/
 
+// Lets the derive macros refer to `ag_grid_rs` from within this crate too.
+extern crate self as ag_grid_rs;
+
 pub mod callbacks;
 pub mod column;
+pub mod components;
+pub mod events;
 pub mod filter;
 pub mod grid;
 pub mod gridoptions;
+pub mod row;
 mod shared;
 pub mod sort;
+#[cfg(feature = "sql")]
+pub mod sql;
 #[doc(hidden)]
 mod types;
 
 pub use ag_grid_core::convert;
 #[doc(hidden)]
 pub use ag_grid_core::imports;
-pub use ag_grid_derive::ToJsValue;
+pub use ag_grid_derive::{FromJsValue, ToJsValue};
 pub use column::{ColumnApi, ColumnDef};
 pub use grid::{Grid, GridApi};
 pub use gridoptions::GridOptions;
+pub use row::ColumnValues;
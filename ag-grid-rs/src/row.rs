@@ -0,0 +1,610 @@
+//! Types pertaining to the grid's rows.
+//!
+//! # Row transactions
+//!
+//! With the client-side row model, rows can be added, updated and removed
+//! without replacing all of the row data by applying a [`RowDataTransaction`]
+//! with [`GridApi::apply_transaction`], or with
+//! [`GridApi::apply_transaction_async`] to batch frequent updates.
+//!
+//! # Evaluating models in memory
+//!
+//! Rather than sending a request to a server, a datasource can filter, sort and
+//! slice rows it already holds with [`GetRowsParams::apply`]. The row type
+//! exposes its values by column ID through [`ColumnValues`], which can be
+//! derived:
+//!
+//! ```rust,no_run
+//! # use ag_grid_rs::{gridoptions::DataSourceBuilder, ColumnValues, ToJsValue};
+//! # async fn fetch_athletes() -> Result<Vec<Athlete>, Box<dyn std::error::Error>> {
+//! #     Ok(Vec::new())
+//! # }
+//! #[derive(ColumnValues, ToJsValue)]
+//! struct Athlete {
+//!     athlete: String,
+//!     age: Option<usize>,
+//! }
+//!
+//! let data_source = DataSourceBuilder::new(move |params| async move {
+//!     let mut rows = fetch_athletes().await?;
+//!     let last_row = params.apply(&mut rows);
+//!     Ok((rows, Some(last_row as u32)))
+//! })
+//! .build();
+//! ```
+//!
+//! [`GetRowsParams::apply`]: crate::callbacks::GetRowsParams::apply
+//! [`GridApi::apply_transaction`]: crate::GridApi::apply_transaction
+//! [`GridApi::apply_transaction_async`]: crate::GridApi::apply_transaction_async
+
+use std::{cmp::Ordering, fmt, marker::PhantomData};
+
+use ag_grid_core::convert::{FromJsValue, FromJsValueError, ToJsValue};
+pub use ag_grid_derive::ColumnValues;
+use ag_grid_derive::{FromInterface, ToJsValue};
+use chrono::{NaiveDate, NaiveDateTime};
+use wasm_bindgen::{prelude::*, JsCast};
+
+use crate::{
+    filter::{
+        CombinedFilterModel, Comparator, FilterModel, FilterModelType, JoinOperator, MultiFilter,
+        SetFilter,
+    },
+    sort::{SortDirection, SortModelItem},
+};
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone)]
+    pub(crate) type IRowNode;
+
+    #[wasm_bindgen(method, getter)]
+    fn id(this: &IRowNode) -> Option<String>;
+
+    #[wasm_bindgen(method, getter)]
+    fn data(this: &IRowNode) -> JsValue;
+
+    #[wasm_bindgen(method, getter, js_name = rowIndex)]
+    fn row_index(this: &IRowNode) -> Option<u32>;
+
+    #[wasm_bindgen(method, getter, js_name = rowHeight)]
+    fn row_height(this: &IRowNode) -> Option<u32>;
+
+    #[wasm_bindgen(method, js_name = setData)]
+    fn set_data(this: &IRowNode, data: JsValue);
+
+    #[wasm_bindgen(method, js_name = setDataValue)]
+    fn set_data_value(this: &IRowNode, col_key: &str, new_value: JsValue);
+
+    #[wasm_bindgen(method, js_name = isSelected)]
+    fn is_selected(this: &IRowNode) -> Option<bool>;
+
+    #[wasm_bindgen(method, js_name = setSelected)]
+    pub(crate) fn set_selected(this: &IRowNode, new_value: bool, clear_selection: bool);
+
+    #[wasm_bindgen(method, js_name = setRowHeight)]
+    fn set_row_height(this: &IRowNode, row_height: Option<u32>);
+}
+
+/// A handle to a row in the grid, whose data is of type `T`.
+pub struct RowNode<T> {
+    node: IRowNode,
+    _data: PhantomData<T>,
+}
+
+impl<T> RowNode<T> {
+    /// The ID of the row, as given by
+    /// [`GridOptions::get_row_id`][crate::GridOptions::get_row_id] or
+    /// otherwise generated by the grid.
+    pub fn id(&self) -> Option<String> {
+        self.node.id()
+    }
+
+    /// The index of the row as displayed, or `None` if it is not displayed,
+    /// e.g. because it has been filtered out.
+    pub fn row_index(&self) -> Option<u32> {
+        self.node.row_index()
+    }
+
+    /// Returns `true` if the row is selected.
+    pub fn is_selected(&self) -> bool {
+        self.node.is_selected().unwrap_or(false)
+    }
+
+    /// Select or deselect the row.
+    pub fn set_selected(&self, selected: bool) {
+        self.node.set_selected(selected, false)
+    }
+
+    /// The height of the row in pixels.
+    pub fn row_height(&self) -> Option<u32> {
+        self.node.row_height()
+    }
+
+    /// Set the height of the row in pixels, or `None` to reset it to the
+    /// default. Call
+    /// [`GridApi::on_row_height_changed`][crate::GridApi::on_row_height_changed]
+    /// afterwards for the grid to lay out the rows again.
+    pub fn set_row_height(&self, row_height: Option<u32>) {
+        self.node.set_row_height(row_height)
+    }
+
+    /// Set the value of a single column of the row, as if it had been edited.
+    pub fn set_data_value<V: ToJsValue>(&self, col_id: &str, value: V) {
+        self.node.set_data_value(col_id, value.to_js_value())
+    }
+
+    /// The underlying JavaScript row node.
+    pub(crate) fn node(&self) -> &IRowNode {
+        &self.node
+    }
+}
+
+impl<T: FromJsValue> RowNode<T> {
+    /// Decode the data of the row. Returns `None` if the row has no data, such
+    /// as a row which is still loading, or an error if the data cannot be
+    /// decoded into a `T`.
+    pub fn data(&self) -> Result<Option<T>, FromJsValueError> {
+        FromJsValue::from_js_value(&self.node.data())
+    }
+}
+
+impl<T: ToJsValue> RowNode<T> {
+    /// Replace the data of the row, refreshing its cells.
+    pub fn set_data(&self, data: T) {
+        self.node.set_data(data.to_js_value())
+    }
+}
+
+impl<T> Clone for RowNode<T> {
+    fn clone(&self) -> Self {
+        self.node.clone().into()
+    }
+}
+
+impl<T> fmt::Debug for RowNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowNode")
+            .field("id", &self.id())
+            .field("row_index", &self.row_index())
+            .finish()
+    }
+}
+
+impl<T> From<IRowNode> for RowNode<T> {
+    fn from(node: IRowNode) -> Self {
+        Self {
+            node,
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<T> From<&IRowNode> for RowNode<T> {
+    fn from(node: &IRowNode) -> Self {
+        node.clone().into()
+    }
+}
+
+impl<T> FromJsValue for RowNode<T> {
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        if !value.is_object() {
+            return Err(FromJsValueError::invalid_type("a row node", value));
+        }
+        Ok(value.unchecked_ref::<IRowNode>().into())
+    }
+}
+
+/// A type with a value which uniquely identifies each row, for use with
+/// [`GridOptions::get_row_id`][crate::GridOptions::get_row_id].
+///
+/// This trait can be derived by marking a field of a struct deriving
+/// `ToJsValue` with `#[js_value(row_id)]`. The ID is the field's `to_string()`
+/// value.
+pub trait RowId {
+    /// Returns the ID of the row.
+    fn row_id(&self) -> String;
+}
+
+/// Changes to apply to the grid's row data with
+/// [`GridApi::apply_transaction`][crate::GridApi::apply_transaction].
+///
+/// Rows are serialized afresh for each transaction, and so updated and removed
+/// rows are matched to the grid's rows by the ID given by
+/// [`GridOptions::get_row_id`][crate::GridOptions::get_row_id].
+#[derive(Debug, ToJsValue)]
+#[js_value(skip_serializing_none)]
+pub struct RowDataTransaction<T> {
+    /// Rows to add.
+    pub add: Vec<T>,
+    /// The index at which to add rows. By default, rows are added to the end.
+    pub add_index: Option<u32>,
+    /// Rows to update.
+    pub update: Vec<T>,
+    /// Rows to remove.
+    pub remove: Vec<T>,
+}
+
+impl<T> RowDataTransaction<T> {
+    /// Create a new, empty, transaction.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<T> Default for RowDataTransaction<T> {
+    fn default() -> Self {
+        Self {
+            add: Vec::new(),
+            add_index: None,
+            update: Vec::new(),
+            remove: Vec::new(),
+        }
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type IRowNodeTransaction;
+
+    #[wasm_bindgen(method, getter)]
+    fn add(this: &IRowNodeTransaction) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn update(this: &IRowNodeTransaction) -> JsValue;
+
+    #[wasm_bindgen(method, getter)]
+    fn remove(this: &IRowNodeTransaction) -> JsValue;
+}
+
+/// The rows affected by a [`RowDataTransaction`].
+#[derive(Debug, FromInterface)]
+pub struct RowNodeTransaction<T> {
+    /// The rows which were added.
+    pub add: Vec<RowNode<T>>,
+    /// The rows which were updated.
+    pub update: Vec<RowNode<T>>,
+    /// The rows which were removed.
+    pub remove: Vec<RowNode<T>>,
+}
+
+impl<T: FromJsValue> FromJsValue for RowNodeTransaction<T> {
+    fn from_js_value(value: &JsValue) -> Result<Self, FromJsValueError> {
+        if !value.is_object() {
+            return Err(FromJsValueError::invalid_type(
+                "a row node transaction",
+                value,
+            ));
+        }
+        value.unchecked_ref::<IRowNodeTransaction>().try_into()
+    }
+}
+
+/// Provides the value of each column of a row, keyed by column ID.
+///
+/// This trait can be derived for structs with named fields. The column ID of
+/// each field is its camelCase name, matching the key the field is serialized
+/// to by the `ToJsValue` derive, and so the `field` of a [`ColumnDef`]. As
+/// with `ToJsValue`, `#[js_value(rename = "...")]` overrides the name. Fields
+/// whose type does not implement [`ToCellValue`] can be excluded with
+/// `#[column_values(skip)]`.
+///
+/// [`ColumnDef`]: crate::ColumnDef
+pub trait ColumnValues {
+    /// Returns the value of the given column, or `None` if the row has no such
+    /// column.
+    fn column_value(&self, col_id: &str) -> Option<CellValue>;
+}
+
+/// The value of a single cell, as seen by the filter and sort models.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    DateTime(NaiveDateTime),
+}
+
+impl CellValue {
+    /// The value as it is matched against text and set filters.
+    fn as_text(&self) -> Option<String> {
+        match self {
+            Self::Null => None,
+            Self::Bool(b) => Some(b.to_string()),
+            Self::Number(n) => Some(n.to_string()),
+            Self::Text(s) => Some(s.clone()),
+            Self::DateTime(d) => Some(d.to_string()),
+        }
+    }
+
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn as_date_time(&self) -> Option<NaiveDateTime> {
+        match self {
+            Self::DateTime(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    /// Order values as the grid does by default: empty values first, then
+    /// values of the same kind by their natural ordering.
+    fn sort_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Number(a), Self::Number(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            (Self::Text(a), Self::Text(b)) => a.cmp(b),
+            (Self::DateTime(a), Self::DateTime(b)) => a.cmp(b),
+            (a, b) => a.rank().cmp(&b.rank()),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Null => 0,
+            Self::Bool(_) => 1,
+            Self::Number(_) => 2,
+            Self::Text(_) => 3,
+            Self::DateTime(_) => 4,
+        }
+    }
+}
+
+/// Conversion of a field into a [`CellValue`], used by the [`ColumnValues`]
+/// derive.
+pub trait ToCellValue {
+    fn to_cell_value(&self) -> CellValue;
+}
+
+impl ToCellValue for CellValue {
+    fn to_cell_value(&self) -> CellValue {
+        self.clone()
+    }
+}
+
+impl<T: ToCellValue + ?Sized> ToCellValue for &T {
+    fn to_cell_value(&self) -> CellValue {
+        (*self).to_cell_value()
+    }
+}
+
+impl<T: ToCellValue> ToCellValue for Option<T> {
+    fn to_cell_value(&self) -> CellValue {
+        match self {
+            Some(value) => value.to_cell_value(),
+            None => CellValue::Null,
+        }
+    }
+}
+
+impl ToCellValue for bool {
+    fn to_cell_value(&self) -> CellValue {
+        CellValue::Bool(*self)
+    }
+}
+
+impl ToCellValue for str {
+    fn to_cell_value(&self) -> CellValue {
+        CellValue::Text(self.to_string())
+    }
+}
+
+impl ToCellValue for String {
+    fn to_cell_value(&self) -> CellValue {
+        CellValue::Text(self.clone())
+    }
+}
+
+impl ToCellValue for char {
+    fn to_cell_value(&self) -> CellValue {
+        CellValue::Text(self.to_string())
+    }
+}
+
+impl ToCellValue for NaiveDateTime {
+    fn to_cell_value(&self) -> CellValue {
+        CellValue::DateTime(*self)
+    }
+}
+
+impl ToCellValue for NaiveDate {
+    fn to_cell_value(&self) -> CellValue {
+        CellValue::DateTime(self.and_hms_opt(0, 0, 0).unwrap())
+    }
+}
+
+macro_rules! number_to_cell_value {
+    ($($t:ty),*) => {
+        $(
+            impl ToCellValue for $t {
+                fn to_cell_value(&self) -> CellValue {
+                    CellValue::Number(*self as f64)
+                }
+            }
+        )*
+    };
+}
+
+number_to_cell_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Returns `true` if the row passes the filter model of the given column.
+///
+/// Custom filter models cannot be evaluated here, and so pass every row.
+pub(crate) fn passes_filter<T: ColumnValues>(
+    row: &T,
+    col_id: &str,
+    model: &FilterModelType,
+) -> bool {
+    let value = row.column_value(col_id).unwrap_or(CellValue::Null);
+    passes_filter_model_type(&value, model)
+}
+
+fn passes_filter_model_type(value: &CellValue, model: &FilterModelType) -> bool {
+    match model {
+        FilterModelType::Single(model) => passes_filter_model(value, model),
+        FilterModelType::Combined(model) => passes_combined_filter_model(value, model),
+        FilterModelType::Custom(_) => true,
+    }
+}
+
+fn passes_filter_model(value: &CellValue, model: &FilterModel) -> bool {
+    match model {
+        FilterModel::Text(f) => {
+            let lowered = |s: &Option<String>| s.as_ref().map(|s| s.to_lowercase());
+            let comparator = f.comparator.as_ref();
+            let cell = value.as_text().map(|s| s.to_lowercase());
+            let (from, to) = (lowered(&f.filter), lowered(&f.filter_to));
+
+            let text = |matches: fn(&str, &str) -> bool, negate: bool| match (&cell, &from) {
+                (_, None) => true,
+                (Some(cell), Some(from)) => matches(cell, from) != negate,
+                (None, Some(_)) => negate,
+            };
+            match comparator {
+                Some(Comparator::Contains) => text(|cell, from| cell.contains(from), false),
+                Some(Comparator::NotContains) => text(|cell, from| cell.contains(from), true),
+                Some(Comparator::StartsWith) => text(|cell, from| cell.starts_with(from), false),
+                Some(Comparator::EndsWith) => text(|cell, from| cell.ends_with(from), false),
+                Some(Comparator::Blank) => match cell {
+                    Some(s) => s.is_empty(),
+                    None => true,
+                },
+                Some(Comparator::NotBlank) => cell.as_ref().is_some_and(|s| !s.is_empty()),
+                _ => passes_condition(comparator, cell, from, to),
+            }
+        }
+        FilterModel::Number(f) => passes_condition(
+            f.comparator.as_ref(),
+            value.as_number(),
+            f.filter,
+            f.filter_to,
+        ),
+        FilterModel::Date(f) => passes_condition(
+            f.comparator.as_ref(),
+            value.as_date_time(),
+            f.filter,
+            f.filter_to,
+        ),
+        FilterModel::Set(f) => passes_set_filter(value, f),
+        FilterModel::Multi(f) => passes_multi_filter(value, f),
+    }
+}
+
+fn passes_combined_filter_model(value: &CellValue, model: &CombinedFilterModel) -> bool {
+    let (operator, conditions) = match model {
+        CombinedFilterModel::Text(f) => (
+            &f.operator,
+            f.conditions
+                .iter()
+                .map(|c| FilterModel::Text(c.clone()))
+                .collect::<Vec<_>>(),
+        ),
+        CombinedFilterModel::Number(f) => (
+            &f.operator,
+            f.conditions
+                .iter()
+                .map(|c| FilterModel::Number(c.clone()))
+                .collect(),
+        ),
+        CombinedFilterModel::Date(f) => (
+            &f.operator,
+            f.conditions
+                .iter()
+                .map(|c| FilterModel::Date(c.clone()))
+                .collect(),
+        ),
+    };
+
+    let mut results = conditions
+        .iter()
+        .map(|condition| passes_filter_model(value, condition));
+    match operator {
+        JoinOperator::And => results.all(|passes| passes),
+        JoinOperator::Or => conditions.is_empty() || results.any(|passes| passes),
+    }
+}
+
+fn passes_set_filter(value: &CellValue, filter: &SetFilter) -> bool {
+    let value = value.as_text();
+    filter.values.contains(&value)
+}
+
+fn passes_multi_filter(value: &CellValue, filter: &MultiFilter) -> bool {
+    filter
+        .filter_models
+        .iter()
+        .flatten()
+        .all(|model| passes_filter_model_type(value, model))
+}
+
+/// Evaluate a single condition which compares values by their ordering. As
+/// with the grid's own filters, a condition missing the value it compares
+/// against does not constrain the rows, and `InRange` excludes its bounds.
+fn passes_condition<V: PartialOrd>(
+    comparator: Option<&Comparator>,
+    cell: Option<V>,
+    from: Option<V>,
+    to: Option<V>,
+) -> bool {
+    let comparator = match comparator {
+        None | Some(Comparator::ChooseOne) => return true,
+        Some(comparator) => comparator,
+    };
+
+    match comparator {
+        Comparator::Blank => return cell.is_none(),
+        Comparator::NotBlank => return cell.is_some(),
+        _ => {}
+    }
+
+    let from = match from {
+        Some(from) => from,
+        None => return true,
+    };
+    let cell = match cell {
+        Some(cell) => cell,
+        None => return matches!(comparator, Comparator::NotEquals),
+    };
+
+    match comparator {
+        Comparator::Equals => cell == from,
+        Comparator::NotEquals => cell != from,
+        Comparator::LessThan => cell < from,
+        Comparator::LessThanOrEqual => cell <= from,
+        Comparator::GreaterThan => cell > from,
+        Comparator::GreaterThanOrEqual => cell >= from,
+        Comparator::InRange => match to {
+            Some(to) => cell > from && cell < to,
+            None => true,
+        },
+        // Text comparators only apply to text filters, which handle them.
+        _ => true,
+    }
+}
+
+/// Compare two rows by each column of the sort model in turn.
+pub(crate) fn compare_rows<T: ColumnValues>(
+    a: &T,
+    b: &T,
+    sort_model: &[SortModelItem],
+) -> Ordering {
+    for item in sort_model {
+        let a = a.column_value(&item.col_id).unwrap_or(CellValue::Null);
+        let b = b.column_value(&item.col_id).unwrap_or(CellValue::Null);
+
+        let ordering = match item.sort {
+            SortDirection::Desc => b.sort_cmp(&a),
+            _ => a.sort_cmp(&b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
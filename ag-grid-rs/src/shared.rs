@@ -2,10 +2,65 @@ use ag_grid_derive::ToJsValue;
 
 /// Allowed values for [`ColumnDef::sort`][crate::ColumnDef::sort] and related
 /// methods.
-#[derive(ToJsValue)]
+#[derive(ToJsValue, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortMethod {
     Asc,
     Desc,
     #[js_value(serialize_as = "null")]
     Null,
 }
+
+impl SortMethod {
+    /// Convert the sort string used by AG Grid, where a missing value means the
+    /// column is not sorted.
+    pub(crate) fn from_js_sort(sort: Option<String>) -> Self {
+        match sort.as_deref() {
+            Some("asc") => Self::Asc,
+            Some("desc") => Self::Desc,
+            _ => Self::Null,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SortMethod {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Asc => s.serialize_str("asc"),
+            Self::Desc => s.serialize_str("desc"),
+            Self::Null => s.serialize_none(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SortMethod {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        match Option::<String>::deserialize(d)?.as_deref() {
+            Some("asc") => Ok(Self::Asc),
+            Some("desc") => Ok(Self::Desc),
+            None => Ok(Self::Null),
+            Some(other) => Err(D::Error::unknown_variant(other, &["asc", "desc"])),
+        }
+    }
+}
+
+/// Deserialize a field such that an explicit `null` is passed on to the inner
+/// type, rather than becoming `None`. Combined with `#[serde(default)]`, a
+/// missing field is still deserialized to `None`.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_some<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    T::deserialize(d).map(Some)
+}
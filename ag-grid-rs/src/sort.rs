@@ -1,6 +1,8 @@
 //! Types pertaining to grid sorting.
 
 use ag_grid_derive::FromInterface;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -16,6 +18,8 @@ extern "C" {
 
 /// Details of how to sort the requested data.
 #[derive(Debug, FromInterface)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct SortModelItem {
     /// Which column to sort.
     pub col_id: String,
@@ -26,6 +30,8 @@ pub struct SortModelItem {
 /// Possible directions for which to sort data.
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum SortDirection {
     Asc = "asc",
     Desc = "desc",
@@ -0,0 +1,461 @@
+//! Translate the [`GetRowsParams`] received by a datasource into a SQL query.
+//!
+//! This module requires the `sql` feature.
+//!
+//! Column IDs come from the client, so only the columns registered with
+//! [`QueryBuilder::column`] can be filtered and sorted on. All filter values
+//! are returned as bind values rather than being written into the SQL.
+//!
+//! ```rust,no_run
+//! # use ag_grid_rs::{callbacks::GetRowsParams, sql::{Dialect, QueryBuilder}};
+//! # fn query(params: GetRowsParams) -> Result<(), Box<dyn std::error::Error>> {
+//! let query = QueryBuilder::new(Dialect::Postgres)
+//!     .column("athlete", "athlete")
+//!     .column("country", "c.name")
+//!     .build(&params)?;
+//!
+//! let sql = format!("SELECT * FROM results JOIN countries c USING (country_id) {}", query.sql);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::HashMap, error::Error, fmt};
+
+use chrono::NaiveDateTime;
+
+use crate::{
+    callbacks::GetRowsParams,
+    filter::{
+        CombinedFilterModel, Comparator, FilterModel, FilterModelType, JoinOperator, MultiFilter,
+        SetFilter,
+    },
+    sort::SortDirection,
+};
+
+/// The SQL dialect to generate placeholders for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    /// Use `?` placeholders.
+    Sqlite,
+    /// Use numbered `$1`, `$2`, ... placeholders.
+    Postgres,
+}
+
+/// A value to be bound to a placeholder in a [`Query`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    Text(String),
+    Number(f64),
+    DateTime(NaiveDateTime),
+}
+
+/// The `WHERE`, `ORDER BY`, `LIMIT` and `OFFSET` clauses for a request, along
+/// with the values to bind to its placeholders, in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    /// The SQL clauses, e.g. `WHERE "age" > ? ORDER BY "age" DESC NULLS LAST
+    /// LIMIT 100 OFFSET 0`. Clauses which are not needed are omitted.
+    pub sql: String,
+    /// The values to bind to the placeholders in `sql`.
+    pub values: Vec<SqlValue>,
+}
+
+/// The error returned when a [`Query`] could not be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// The column has not been registered with [`QueryBuilder::column`].
+    UnknownColumn(String),
+    /// The filter applied to the column cannot be translated into SQL, e.g.
+    /// because it is a custom filter component.
+    UnsupportedFilter(String),
+    /// The sort direction of the column is not recognised.
+    UnsupportedSort(String),
+    /// A filter condition on the column is missing the value it compares
+    /// against.
+    MissingValue(String),
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownColumn(col_id) => write!(f, "unknown column `{col_id}`"),
+            Self::UnsupportedFilter(col_id) => {
+                write!(f, "the filter on column `{col_id}` is not supported")
+            }
+            Self::UnsupportedSort(col_id) => {
+                write!(f, "the sort on column `{col_id}` is not supported")
+            }
+            Self::MissingValue(col_id) => {
+                write!(
+                    f,
+                    "a filter condition on column `{col_id}` is missing its value"
+                )
+            }
+        }
+    }
+}
+
+impl Error for QueryError {}
+
+/// Builds a [`Query`] from [`GetRowsParams`].
+#[derive(Debug, Clone)]
+pub struct QueryBuilder {
+    dialect: Dialect,
+    columns: HashMap<String, String>,
+}
+
+impl QueryBuilder {
+    /// Create a new builder for the given dialect, with no columns.
+    pub fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            columns: HashMap::new(),
+        }
+    }
+
+    /// Allow filtering and sorting on the column with the given ID, using the
+    /// SQL expression `expr`. The expression is written into the query as is,
+    /// so it must not come from user input.
+    pub fn column<C: AsRef<str>, E: AsRef<str>>(mut self, col_id: C, expr: E) -> Self {
+        self.columns
+            .insert(col_id.as_ref().to_string(), expr.as_ref().to_string());
+        self
+    }
+
+    /// Build the query for the given request.
+    ///
+    /// Filters behave as the provided AG Grid filters do by default: text
+    /// comparisons are case-insensitive and `InRange` excludes its bounds.
+    /// Likewise, nulls sort before every other value.
+    pub fn build(&self, params: &GetRowsParams) -> Result<Query, QueryError> {
+        let mut writer = Writer {
+            builder: self,
+            values: Vec::new(),
+        };
+        let mut clauses = Vec::new();
+
+        // Sort the filters so that the placeholders are in a predictable order.
+        let mut filters = params.filter_model.iter().collect::<Vec<_>>();
+        filters.sort_by_key(|(col_id, _)| *col_id);
+
+        let mut conditions = Vec::new();
+        for (col_id, model) in filters {
+            if let Some(condition) = writer.filter_model_type(col_id, model)? {
+                conditions.push(condition);
+            }
+        }
+        if !conditions.is_empty() {
+            clauses.push(format!("WHERE {}", conditions.join(" AND ")));
+        }
+
+        let order_by = params
+            .sort_model
+            .iter()
+            .map(|item| {
+                // Nulls sort before every other value, as they do in the
+                // grid, rather than in the database's default order.
+                let direction = match item.sort {
+                    SortDirection::Asc => "ASC NULLS FIRST",
+                    SortDirection::Desc => "DESC NULLS LAST",
+                    _ => return Err(QueryError::UnsupportedSort(item.col_id.clone())),
+                };
+                Ok(format!("{} {direction}", self.expr(&item.col_id)?))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if !order_by.is_empty() {
+            clauses.push(format!("ORDER BY {}", order_by.join(", ")));
+        }
+
+        clauses.push(format!(
+            "LIMIT {} OFFSET {}",
+            params.end_row.saturating_sub(params.start_row),
+            params.start_row
+        ));
+
+        Ok(Query {
+            sql: clauses.join(" "),
+            values: writer.values,
+        })
+    }
+
+    fn expr(&self, col_id: &str) -> Result<&str, QueryError> {
+        self.columns
+            .get(col_id)
+            .map(String::as_str)
+            .ok_or_else(|| QueryError::UnknownColumn(col_id.to_string()))
+    }
+}
+
+/// Whether a column holds text, which is compared case-insensitively.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Text,
+    Other,
+}
+
+/// Accumulates the bind values while the conditions of a query are written.
+struct Writer<'a> {
+    builder: &'a QueryBuilder,
+    values: Vec<SqlValue>,
+}
+
+impl Writer<'_> {
+    /// Add a bind value, returning its placeholder.
+    fn bind(&mut self, value: SqlValue) -> String {
+        self.values.push(value);
+        match self.builder.dialect {
+            Dialect::Sqlite => "?".to_string(),
+            Dialect::Postgres => format!("${}", self.values.len()),
+        }
+    }
+
+    fn filter_model_type(
+        &mut self,
+        col_id: &str,
+        model: &FilterModelType,
+    ) -> Result<Option<String>, QueryError> {
+        match model {
+            FilterModelType::Single(model) => self.filter_model(col_id, model),
+            FilterModelType::Combined(model) => self.combined_filter_model(col_id, model),
+            FilterModelType::Custom(_) => Err(QueryError::UnsupportedFilter(col_id.to_string())),
+        }
+    }
+
+    fn filter_model(
+        &mut self,
+        col_id: &str,
+        model: &FilterModel,
+    ) -> Result<Option<String>, QueryError> {
+        match model {
+            FilterModel::Text(f) => self.condition(
+                col_id,
+                Kind::Text,
+                f.comparator.as_ref(),
+                f.filter.clone().map(SqlValue::Text),
+                f.filter_to.clone().map(SqlValue::Text),
+            ),
+            FilterModel::Number(f) => self.condition(
+                col_id,
+                Kind::Other,
+                f.comparator.as_ref(),
+                f.filter.map(SqlValue::Number),
+                f.filter_to.map(SqlValue::Number),
+            ),
+            FilterModel::Date(f) => self.condition(
+                col_id,
+                Kind::Other,
+                f.comparator.as_ref(),
+                f.filter.map(SqlValue::DateTime),
+                f.filter_to.map(SqlValue::DateTime),
+            ),
+            FilterModel::Set(f) => self.set_filter(col_id, f).map(Some),
+            FilterModel::Multi(f) => self.multi_filter(col_id, f),
+        }
+    }
+
+    fn combined_filter_model(
+        &mut self,
+        col_id: &str,
+        model: &CombinedFilterModel,
+    ) -> Result<Option<String>, QueryError> {
+        let (operator, conditions) = match model {
+            CombinedFilterModel::Text(f) => (
+                &f.operator,
+                f.conditions
+                    .iter()
+                    .map(|c| FilterModel::Text(c.clone()))
+                    .collect::<Vec<_>>(),
+            ),
+            CombinedFilterModel::Number(f) => (
+                &f.operator,
+                f.conditions
+                    .iter()
+                    .map(|c| FilterModel::Number(c.clone()))
+                    .collect(),
+            ),
+            CombinedFilterModel::Date(f) => (
+                &f.operator,
+                f.conditions
+                    .iter()
+                    .map(|c| FilterModel::Date(c.clone()))
+                    .collect(),
+            ),
+        };
+
+        let bound = self.values.len();
+        let mut sql = Vec::new();
+        for condition in &conditions {
+            match self.filter_model(col_id, condition)? {
+                Some(condition) => sql.push(condition),
+                // A condition which does not constrain the rows lets every row
+                // through an `OR`, so the whole group is unconstrained.
+                None if matches!(operator, JoinOperator::Or) => {
+                    self.values.truncate(bound);
+                    return Ok(None);
+                }
+                None => {}
+            }
+        }
+
+        let operator = match operator {
+            JoinOperator::And => " AND ",
+            JoinOperator::Or => " OR ",
+        };
+        Ok(join(sql, operator))
+    }
+
+    fn set_filter(&mut self, col_id: &str, filter: &SetFilter) -> Result<String, QueryError> {
+        let expr = self.builder.expr(col_id)?;
+
+        let placeholders = filter
+            .values
+            .iter()
+            .flatten()
+            .map(|value| self.bind(SqlValue::Text(value.clone())))
+            .collect::<Vec<_>>();
+
+        let mut sql = Vec::new();
+        if !placeholders.is_empty() {
+            sql.push(format!("{expr} IN ({})", placeholders.join(", ")));
+        }
+        if filter.values.iter().any(Option::is_none) {
+            sql.push(format!("{expr} IS NULL"));
+        }
+
+        // With nothing selected, no rows pass the filter.
+        Ok(join(sql, " OR ").unwrap_or_else(|| "1 = 0".to_string()))
+    }
+
+    fn multi_filter(
+        &mut self,
+        col_id: &str,
+        filter: &MultiFilter,
+    ) -> Result<Option<String>, QueryError> {
+        let mut sql = Vec::new();
+        for model in filter.filter_models.iter().flatten() {
+            if let Some(condition) = self.filter_model_type(col_id, model)? {
+                sql.push(condition);
+            }
+        }
+        Ok(join(sql, " AND "))
+    }
+
+    /// Write a single filter condition. Returns `None` if the condition does
+    /// not constrain the rows.
+    fn condition(
+        &mut self,
+        col_id: &str,
+        kind: Kind,
+        comparator: Option<&Comparator>,
+        from: Option<SqlValue>,
+        to: Option<SqlValue>,
+    ) -> Result<Option<String>, QueryError> {
+        let expr = self.builder.expr(col_id)?;
+        let comparator = match comparator {
+            None | Some(Comparator::ChooseOne) => return Ok(None),
+            Some(comparator) => comparator,
+        };
+
+        let missing = || QueryError::MissingValue(col_id.to_string());
+        let lowered = |value: Option<SqlValue>| match value {
+            Some(SqlValue::Text(text)) if kind == Kind::Text => {
+                Some(SqlValue::Text(text.to_lowercase()))
+            }
+            value => value,
+        };
+        let like = |value: Option<SqlValue>, prefix: &str, suffix: &str| match lowered(value) {
+            Some(SqlValue::Text(text)) => Ok(SqlValue::Text(format!(
+                "{prefix}{}{suffix}",
+                escape_like(&text)
+            ))),
+            Some(_) => Err(QueryError::UnsupportedFilter(col_id.to_string())),
+            None => Err(missing()),
+        };
+
+        let value = if kind == Kind::Text {
+            format!("LOWER({expr})")
+        } else {
+            expr.to_string()
+        };
+
+        let sql = match comparator {
+            Comparator::Equals => {
+                let from = lowered(from).ok_or_else(missing)?;
+                format!("{value} = {}", self.bind(from))
+            }
+            Comparator::NotEquals => {
+                let from = lowered(from).ok_or_else(missing)?;
+                format!("({expr} IS NULL OR {value} <> {})", self.bind(from))
+            }
+            Comparator::Contains => {
+                let from = like(from, "%", "%")?;
+                format!("{value} LIKE {} ESCAPE '\\'", self.bind(from))
+            }
+            Comparator::NotContains => {
+                let from = like(from, "%", "%")?;
+                format!(
+                    "({expr} IS NULL OR {value} NOT LIKE {} ESCAPE '\\')",
+                    self.bind(from)
+                )
+            }
+            Comparator::StartsWith => {
+                let from = like(from, "", "%")?;
+                format!("{value} LIKE {} ESCAPE '\\'", self.bind(from))
+            }
+            Comparator::EndsWith => {
+                let from = like(from, "%", "")?;
+                format!("{value} LIKE {} ESCAPE '\\'", self.bind(from))
+            }
+            Comparator::LessThan => {
+                let from = lowered(from).ok_or_else(missing)?;
+                format!("{value} < {}", self.bind(from))
+            }
+            Comparator::LessThanOrEqual => {
+                let from = lowered(from).ok_or_else(missing)?;
+                format!("{value} <= {}", self.bind(from))
+            }
+            Comparator::GreaterThan => {
+                let from = lowered(from).ok_or_else(missing)?;
+                format!("{value} > {}", self.bind(from))
+            }
+            Comparator::GreaterThanOrEqual => {
+                let from = lowered(from).ok_or_else(missing)?;
+                format!("{value} >= {}", self.bind(from))
+            }
+            Comparator::InRange => {
+                let from = lowered(from).ok_or_else(missing)?;
+                let to = lowered(to).ok_or_else(missing)?;
+                let from = self.bind(from);
+                let to = self.bind(to);
+                format!("({value} > {from} AND {value} < {to})")
+            }
+            Comparator::Blank if kind == Kind::Text => format!("({expr} IS NULL OR {expr} = '')"),
+            Comparator::Blank => format!("{expr} IS NULL"),
+            Comparator::NotBlank if kind == Kind::Text => {
+                format!("({expr} IS NOT NULL AND {expr} <> '')")
+            }
+            Comparator::NotBlank => format!("{expr} IS NOT NULL"),
+            Comparator::ChooseOne => unreachable!("handled above"),
+        };
+
+        Ok(Some(sql))
+    }
+}
+
+/// Join conditions with the given operator, wrapping them in parentheses if
+/// there is more than one.
+fn join(mut conditions: Vec<String>, operator: &str) -> Option<String> {
+    match conditions.len() {
+        0 => None,
+        1 => conditions.pop(),
+        _ => Some(format!("({})", conditions.join(operator))),
+    }
+}
+
+/// Escape the wildcards in a value used with `LIKE ... ESCAPE '\'`.
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
@@ -3,6 +3,8 @@
 use ag_grid_core::convert::ToJsValue;
 use wasm_bindgen::JsValue;
 
+use crate::callbacks::Callback;
+
 pub(crate) enum OneOrMany<T>
 where
     T: ToJsValue,
@@ -40,3 +42,34 @@ where
         Self::Many(v)
     }
 }
+
+/// Either a plain value, or a Rust closure which AG Grid calls to compute the
+/// value.
+pub(crate) enum ValueOrCallback<T>
+where
+    T: ToJsValue,
+{
+    Value(T),
+    Callback(Callback),
+}
+
+impl<T> ToJsValue for ValueOrCallback<T>
+where
+    T: ToJsValue,
+{
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::Value(v) => v.to_js_value(),
+            Self::Callback(f) => f.to_js_value(),
+        }
+    }
+}
+
+impl<T> From<T> for ValueOrCallback<T>
+where
+    T: ToJsValue,
+{
+    fn from(v: T) -> Self {
+        Self::Value(v)
+    }
+}
@@ -0,0 +1,527 @@
+use std::collections::HashMap;
+
+use ag_grid_rs::{
+    callbacks::GetRowsParams,
+    convert::{FromJsValue, ToJsValue},
+    filter::{
+        CombinedFilterModel, CombinedTextFilter, Comparator, FilterModel, FilterModelType,
+        JoinOperator, MultiFilter, NumberFilter, SetFilter, TextFilter,
+    },
+    row::{CellValue, ColumnValues, RowDataTransaction, RowId, RowNodeTransaction},
+    sort::{SortDirection, SortModelItem},
+    GridApi, GridOptions, ToJsValue,
+};
+use js_sys::{Function, Reflect, JSON};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+
+#[derive(Debug, Clone, PartialEq, ColumnValues)]
+struct Athlete {
+    athlete: String,
+    age: Option<u32>,
+    #[js_value(rename = "nation")]
+    country: String,
+    #[column_values(skip)]
+    _notes: Vec<String>,
+}
+
+fn athlete(name: &str, age: Option<u32>, country: &str) -> Athlete {
+    Athlete {
+        athlete: name.to_string(),
+        age,
+        country: country.to_string(),
+        _notes: Vec::new(),
+    }
+}
+
+fn rows() -> Vec<Athlete> {
+    vec![
+        athlete("Michael Phelps", Some(23), "United States"),
+        athlete("Natalie Coughlin", Some(25), "United States"),
+        athlete("Aleksey Nemov", Some(24), "Russia"),
+        athlete("Ian Thorpe", None, "Australia"),
+        athlete("Alicia Coutts", Some(24), "Australia"),
+    ]
+}
+
+fn params(
+    filters: Vec<(&str, FilterModelType)>,
+    sort: Vec<(&str, SortDirection)>,
+    start_row: u32,
+    end_row: u32,
+) -> GetRowsParams {
+    GetRowsParams {
+        start_row,
+        end_row,
+        sort_model: sort
+            .into_iter()
+            .map(|(col_id, sort)| SortModelItem {
+                col_id: col_id.to_string(),
+                sort,
+            })
+            .collect(),
+        filter_model: filters
+            .into_iter()
+            .map(|(col_id, model)| (col_id.to_string(), model))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+fn names(rows: &[Athlete]) -> Vec<&str> {
+    rows.iter().map(|r| r.athlete.as_str()).collect()
+}
+
+#[wasm_bindgen_test]
+fn test_derive_column_values() {
+    let row = athlete("Ian Thorpe", None, "Australia");
+
+    assert_eq!(
+        row.column_value("athlete"),
+        Some(CellValue::Text("Ian Thorpe".to_string()))
+    );
+    assert_eq!(row.column_value("age"), Some(CellValue::Null));
+    assert_eq!(
+        row.column_value("nation"),
+        Some(CellValue::Text("Australia".to_string()))
+    );
+    assert_eq!(row.column_value("country"), None);
+    assert_eq!(row.column_value("notes"), None);
+}
+
+#[wasm_bindgen_test]
+fn test_apply_sort_and_slice() {
+    let mut rows = rows();
+    let sorted = params(
+        vec![],
+        vec![
+            ("age", SortDirection::Desc),
+            ("athlete", SortDirection::Asc),
+        ],
+        1,
+        4,
+    );
+
+    assert_eq!(sorted.apply(&mut rows), 5);
+    assert_eq!(
+        names(&rows),
+        ["Aleksey Nemov", "Alicia Coutts", "Michael Phelps"]
+    );
+
+    // Empty values sort first.
+    let mut rows = self::rows();
+    params(vec![], vec![("age", SortDirection::Asc)], 0, 2).apply(&mut rows);
+    assert_eq!(names(&rows), ["Ian Thorpe", "Michael Phelps"]);
+}
+
+#[wasm_bindgen_test]
+fn test_apply_filters() {
+    let text = |comparator, filter: &str| TextFilter {
+        filter: Some(filter.to_string()),
+        filter_to: None,
+        comparator: Some(comparator),
+    };
+    let number = |comparator, filter, filter_to| NumberFilter {
+        filter: Some(filter),
+        filter_to,
+        comparator: Some(comparator),
+    };
+
+    let mut rows = rows();
+    let count = params(
+        vec![
+            (
+                "nation",
+                FilterModelType::Single(FilterModel::Text(text(Comparator::Contains, "UNITED"))),
+            ),
+            (
+                "age",
+                FilterModelType::Single(FilterModel::Number(number(
+                    Comparator::GreaterThan,
+                    23.0,
+                    None,
+                ))),
+            ),
+        ],
+        vec![],
+        0,
+        100,
+    )
+    .apply(&mut rows);
+    assert_eq!(count, 1);
+    assert_eq!(names(&rows), ["Natalie Coughlin"]);
+
+    // Combined conditions, with an exclusive range.
+    let mut rows = self::rows();
+    params(
+        vec![
+            (
+                "athlete",
+                FilterModelType::Combined(CombinedFilterModel::Text(CombinedTextFilter {
+                    conditions: vec![
+                        text(Comparator::StartsWith, "ali"),
+                        text(Comparator::EndsWith, "nemov"),
+                    ],
+                    operator: JoinOperator::Or,
+                })),
+            ),
+            (
+                "age",
+                FilterModelType::Single(FilterModel::Number(number(
+                    Comparator::InRange,
+                    23.0,
+                    Some(25.0),
+                ))),
+            ),
+        ],
+        vec![("athlete", SortDirection::Asc)],
+        0,
+        100,
+    )
+    .apply(&mut rows);
+    assert_eq!(names(&rows), ["Aleksey Nemov", "Alicia Coutts"]);
+
+    // Set filters match empty values with `None`, and multi filters require
+    // every child filter to pass. Custom filters are ignored.
+    let mut rows = self::rows();
+    params(
+        vec![
+            (
+                "age",
+                FilterModelType::Single(FilterModel::Set(SetFilter {
+                    values: vec![Some("25".to_string()), None],
+                })),
+            ),
+            (
+                "nation",
+                FilterModelType::Single(FilterModel::Multi(MultiFilter {
+                    filter_models: vec![
+                        Some(FilterModelType::Single(FilterModel::Text(text(
+                            Comparator::NotEquals,
+                            "russia",
+                        )))),
+                        None,
+                        Some(FilterModelType::Custom(JsValue::from_str("anything"))),
+                    ],
+                })),
+            ),
+        ],
+        vec![("athlete", SortDirection::Asc)],
+        0,
+        100,
+    )
+    .apply(&mut rows);
+    assert_eq!(names(&rows), ["Ian Thorpe", "Natalie Coughlin"]);
+
+    let mut rows = self::rows();
+    params(
+        vec![(
+            "age",
+            FilterModelType::Single(FilterModel::Number(NumberFilter {
+                filter: None,
+                filter_to: None,
+                comparator: Some(Comparator::Blank),
+            })),
+        )],
+        vec![],
+        0,
+        100,
+    )
+    .apply(&mut rows);
+    assert_eq!(names(&rows), ["Ian Thorpe"]);
+}
+
+#[wasm_bindgen_test]
+fn test_serialize_row_data_transaction() {
+    let transaction = RowDataTransaction {
+        add: vec![1u32, 2],
+        remove: vec![3],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        JSON::stringify(&transaction.to_js_value()).unwrap(),
+        r#"{"add":[1,2],"update":[],"remove":[3]}"#
+    );
+
+    let transaction = RowDataTransaction {
+        add: vec![1u32],
+        add_index: Some(0),
+        ..RowDataTransaction::new()
+    };
+    assert_eq!(
+        JSON::stringify(&transaction.to_js_value()).unwrap(),
+        r#"{"add":[1],"addIndex":0,"update":[],"remove":[]}"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_decode_row_node_transaction() {
+    let result = JSON::parse(
+        r#"{"add": [{"id": "1", "data": 5, "rowIndex": 0}], "update": [], "remove": [{"id": "2"}]}"#,
+    )
+    .unwrap();
+    let result = RowNodeTransaction::<u32>::from_js_value(&result).unwrap();
+
+    assert_eq!(result.add.len(), 1);
+    assert_eq!(result.add[0].id().as_deref(), Some("1"));
+    assert_eq!(result.add[0].data().unwrap(), Some(5));
+    assert_eq!(result.add[0].row_index(), Some(0));
+    assert!(result.update.is_empty());
+    assert_eq!(result.remove[0].data().unwrap(), None);
+    assert_eq!(result.remove[0].row_index(), None);
+
+    assert!(RowNodeTransaction::<u32>::from_js_value(&JsValue::undefined()).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_apply_transaction() {
+    // Stand in for the grid, which returns no result unless it is using the
+    // client-side row model.
+    let api = Function::new_no_args(
+        "return {
+            applyTransaction(transaction) {
+                if (transaction.add.length === 0) { return undefined; }
+                return {
+                    add: transaction.add.map((data, i) => ({ id: String(i), data })),
+                    update: [],
+                    remove: transaction.remove,
+                };
+            },
+        };",
+    )
+    .call0(&JsValue::null())
+    .unwrap()
+    .unchecked_into::<GridApi>();
+
+    let result = api
+        .apply_transaction(RowDataTransaction {
+            add: vec![7u32, 8],
+            ..Default::default()
+        })
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        result
+            .add
+            .iter()
+            .map(|node| node.data().unwrap())
+            .collect::<Vec<_>>(),
+        [Some(7), Some(8)]
+    );
+    assert!(result.remove.is_empty());
+
+    assert!(api
+        .apply_transaction(RowDataTransaction::<u32>::new())
+        .unwrap()
+        .is_none());
+
+    // The removed rows come back as plain numbers rather than row nodes.
+    assert!(api
+        .apply_transaction(RowDataTransaction {
+            add: vec![7u32],
+            remove: vec![8],
+            ..Default::default()
+        })
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_apply_transaction_async() {
+    // Stand in for the grid, which executes the batch once flushed.
+    let api = Function::new_no_args(
+        "let queue = [];
+        return {
+            applyTransactionAsync(transaction, callback) {
+                queue.push(() => callback({
+                    add: transaction.add.map((data, i) => ({ id: String(i), data })),
+                    update: [],
+                    remove: [],
+                }));
+            },
+            flushAsyncTransactions() { queue.splice(0).forEach((f) => f()); },
+        };",
+    )
+    .call0(&JsValue::null())
+    .unwrap()
+    .unchecked_into::<GridApi>();
+
+    let result = api.apply_transaction_async(RowDataTransaction {
+        add: vec![7u32, 8],
+        ..Default::default()
+    });
+    api.flush_async_transactions();
+
+    let result = result.await.unwrap();
+    assert_eq!(
+        result
+            .add
+            .iter()
+            .map(|node| node.data().unwrap())
+            .collect::<Vec<_>>(),
+        [Some(7), Some(8)]
+    );
+
+    // A grid which throws rejects the transaction rather than panicking.
+    let api = Function::new_no_args(
+        "return { applyTransactionAsync() { throw new Error('no grid'); } };",
+    )
+    .call0(&JsValue::null())
+    .unwrap()
+    .unchecked_into::<GridApi>();
+    assert!(api
+        .apply_transaction_async(RowDataTransaction::<u32>::new())
+        .await
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_row_id() {
+    #[derive(ToJsValue)]
+    struct Car {
+        #[js_value(row_id)]
+        registration: u32,
+        make: String,
+    }
+
+    let car = Car {
+        registration: 42,
+        make: "Jaguar".to_string(),
+    };
+    assert_eq!(car.row_id(), "42");
+
+    let grid_options =
+        GridOptions::<Vec<u32>>::new().get_row_id(|row| format!("{}-{}", row[0], row[1]));
+    let get_row_id = Reflect::get(&grid_options.to_js_value(), &"getRowId".into())
+        .unwrap()
+        .unchecked_into::<Function>();
+    let params = JSON::parse(r#"{"data": [1, 2]}"#).unwrap();
+
+    assert_eq!(
+        get_row_id.call1(&JsValue::null(), &params).unwrap(),
+        JsValue::from_str("1-2")
+    );
+
+    // Row data which does not decode is logged rather than panicking.
+    let params = JSON::parse(r#"{"data": "not a row"}"#).unwrap();
+    assert!(get_row_id
+        .call1(&JsValue::null(), &params)
+        .unwrap()
+        .is_undefined());
+}
+
+#[wasm_bindgen_test]
+fn test_selection() {
+    // Stand in for the grid, with two rows of which the first is selected.
+    let api = Function::new_no_args(
+        "const nodes = [1, 2].map((data) => ({
+            id: String(data),
+            data,
+            rowIndex: data - 1,
+            selected: data === 1,
+            setSelected(value, clear) {
+                if (clear) nodes.forEach((node) => node.selected = false);
+                this.selected = value;
+            },
+        }));
+        const selected = () => nodes.filter((node) => node.selected);
+        return {
+            getSelectedNodes: selected,
+            getSelectedRows: () => selected().map((node) => node.data),
+            selectAll() { nodes.forEach((node) => node.selected = true); },
+            deselectAll() { nodes.forEach((node) => node.selected = false); },
+        };",
+    )
+    .call0(&JsValue::null())
+    .unwrap()
+    .unchecked_into::<GridApi>();
+
+    assert_eq!(api.get_selected_rows::<u32>().unwrap(), [1]);
+
+    api.select_all();
+    assert_eq!(api.get_selected_rows::<u32>().unwrap(), [1, 2]);
+
+    let nodes = api.get_selected_nodes::<u32>().unwrap();
+    assert_eq!(nodes[1].id().as_deref(), Some("2"));
+    assert_eq!(nodes[1].row_index(), Some(1));
+
+    api.deselect_all();
+    assert!(api.get_selected_rows::<u32>().unwrap().is_empty());
+
+    api.select_node(&nodes[1], true);
+    assert_eq!(api.get_selected_rows::<u32>().unwrap(), [2]);
+    assert!(api.get_selected_rows::<String>().is_err());
+
+    let grid_options = GridOptions::<u32>::new()
+        .is_row_selectable(|node| matches!(node.data(), Ok(Some(data)) if data % 2 == 0));
+    let is_row_selectable = Reflect::get(&grid_options.to_js_value(), &"isRowSelectable".into())
+        .unwrap()
+        .unchecked_into::<Function>();
+    let node = JSON::parse(r#"{"data": 2}"#).unwrap();
+
+    assert_eq!(
+        is_row_selectable.call1(&JsValue::null(), &node).unwrap(),
+        JsValue::TRUE
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_row_node() {
+    // Stand in for the grid, with three rows of which the second is filtered
+    // out and the others are sorted in reverse.
+    let api = Function::new_no_args(
+        "const nodes = [1, 2, 3].map((value, i) => ({
+            id: String(value),
+            data: { value },
+            rowIndex: [1, null, 0][i],
+            rowHeight: 25,
+            selected: false,
+            setData(data) { this.data = data; },
+            setDataValue(key, value) { this.data[key] = value; },
+            isSelected() { return this.selected; },
+            setSelected(value) { this.selected = value; },
+            setRowHeight(height) { this.rowHeight = height; },
+        }));
+        const displayed = [nodes[2], nodes[0]];
+        return {
+            getRowNode: (id) => nodes.find((node) => node.id === id),
+            forEachNode: (callback) => nodes.forEach(callback),
+            forEachNodeAfterFilterAndSort: (callback) => displayed.forEach(callback),
+            getDisplayedRowAtIndex: (index) => displayed[index],
+        };",
+    )
+    .call0(&JsValue::null())
+    .unwrap()
+    .unchecked_into::<GridApi>();
+
+    let node = api.get_row_node::<HashMap<String, u32>>("2").unwrap();
+    assert_eq!(node.id().as_deref(), Some("2"));
+    assert_eq!(node.row_index(), None);
+    assert_eq!(node.data().unwrap().unwrap()["value"], 2);
+    assert!(api.get_row_node::<HashMap<String, u32>>("4").is_none());
+    assert!(api.get_row_node::<String>("2").unwrap().data().is_err());
+
+    node.set_data_value("value", 20u32);
+    assert_eq!(node.data().unwrap().unwrap()["value"], 20);
+    node.set_data(HashMap::from([("value".to_string(), 200)]));
+    assert_eq!(node.data().unwrap().unwrap()["value"], 200);
+
+    assert!(!node.is_selected());
+    node.set_selected(true);
+    assert!(node.is_selected());
+
+    assert_eq!(node.row_height(), Some(25));
+    node.set_row_height(None);
+    assert_eq!(node.row_height(), None);
+
+    let mut ids = Vec::new();
+    api.for_each_node::<JsValue, _>(|node| ids.push(node.id().unwrap()));
+    assert_eq!(ids, ["1", "2", "3"]);
+
+    let mut ids = Vec::new();
+    api.for_each_node_after_filter_and_sort::<JsValue, _>(|node| ids.push(node.id().unwrap()));
+    assert_eq!(ids, ["3", "1"]);
+
+    let node = api.get_displayed_row_at_index::<JsValue>(1).unwrap();
+    assert_eq!(node.id().as_deref(), Some("1"));
+    assert!(api.get_displayed_row_at_index::<JsValue>(2).is_none());
+}
@@ -0,0 +1,309 @@
+#![cfg(feature = "sql")]
+
+use std::collections::HashMap;
+
+use ag_grid_rs::{
+    callbacks::GetRowsParams,
+    filter::{
+        CombinedFilterModel, CombinedNumberFilter, CombinedTextFilter, Comparator, DateFilter,
+        FilterModel, FilterModelType, JoinOperator, NumberFilter, SetFilter, TextFilter,
+    },
+    sort::{SortDirection, SortModelItem},
+    sql::{Dialect, QueryBuilder, QueryError, SqlValue},
+};
+use chrono::NaiveDateTime;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+fn params(
+    filters: Vec<(&str, FilterModelType)>,
+    sort: Vec<(&str, SortDirection)>,
+) -> GetRowsParams {
+    GetRowsParams {
+        start_row: 100,
+        end_row: 200,
+        sort_model: sort
+            .into_iter()
+            .map(|(col_id, sort)| SortModelItem {
+                col_id: col_id.to_string(),
+                sort,
+            })
+            .collect(),
+        filter_model: filters
+            .into_iter()
+            .map(|(col_id, model)| (col_id.to_string(), model))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+fn text(comparator: Comparator, filter: &str) -> TextFilter {
+    TextFilter {
+        filter: Some(filter.to_string()),
+        filter_to: None,
+        comparator: Some(comparator),
+    }
+}
+
+fn builder(dialect: Dialect) -> QueryBuilder {
+    QueryBuilder::new(dialect)
+        .column("athlete", "\"athlete\"")
+        .column("age", "\"age\"")
+        .column("date", "\"date\"")
+        .column("country", "c.name")
+}
+
+#[wasm_bindgen_test]
+fn test_sort_and_pagination() {
+    let query = builder(Dialect::Sqlite)
+        .build(&params(
+            vec![],
+            vec![
+                ("age", SortDirection::Desc),
+                ("athlete", SortDirection::Asc),
+            ],
+        ))
+        .unwrap();
+
+    assert_eq!(
+        query.sql,
+        r#"ORDER BY "age" DESC NULLS LAST, "athlete" ASC NULLS FIRST LIMIT 100 OFFSET 100"#
+    );
+    assert!(query.values.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_filters() {
+    let date = NaiveDateTime::parse_from_str("2008-08-24 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    let params = params(
+        vec![
+            (
+                "athlete",
+                FilterModelType::Combined(CombinedFilterModel::Text(CombinedTextFilter {
+                    conditions: vec![
+                        text(Comparator::Contains, "50%"),
+                        text(Comparator::NotEquals, "Phelps"),
+                    ],
+                    operator: JoinOperator::Or,
+                })),
+            ),
+            (
+                "age",
+                FilterModelType::Single(FilterModel::Number(NumberFilter {
+                    filter: Some(20.0),
+                    filter_to: Some(30.0),
+                    comparator: Some(Comparator::InRange),
+                })),
+            ),
+            (
+                "date",
+                FilterModelType::Single(FilterModel::Date(DateFilter {
+                    filter: Some(date),
+                    filter_to: None,
+                    comparator: Some(Comparator::GreaterThanOrEqual),
+                })),
+            ),
+            (
+                "country",
+                FilterModelType::Single(FilterModel::Set(SetFilter {
+                    values: vec![Some("Norway".to_string()), None],
+                })),
+            ),
+        ],
+        vec![],
+    );
+
+    let query = builder(Dialect::Postgres).build(&params).unwrap();
+    assert_eq!(
+        query.sql,
+        concat!(
+            r#"WHERE ("age" > $1 AND "age" < $2)"#,
+            r#" AND (LOWER("athlete") LIKE $3 ESCAPE '\' OR ("athlete" IS NULL OR LOWER("athlete") <> $4))"#,
+            r#" AND (c.name IN ($5) OR c.name IS NULL)"#,
+            r#" AND "date" >= $6"#,
+            r#" LIMIT 100 OFFSET 100"#,
+        )
+    );
+    assert_eq!(
+        query.values,
+        [
+            SqlValue::Number(20.0),
+            SqlValue::Number(30.0),
+            SqlValue::Text("%50\\%%".to_string()),
+            SqlValue::Text("phelps".to_string()),
+            SqlValue::Text("Norway".to_string()),
+            SqlValue::DateTime(date),
+        ]
+    );
+
+    let query = builder(Dialect::Sqlite).build(&params).unwrap();
+    assert!(query.sql.starts_with(r#"WHERE ("age" > ? AND "age" < ?)"#));
+    assert!(!query.sql.contains('$'));
+}
+
+#[wasm_bindgen_test]
+fn test_comparators() {
+    let number = |comparator: Comparator, filter: f64| NumberFilter {
+        filter: Some(filter),
+        filter_to: None,
+        comparator: Some(comparator),
+    };
+
+    let query = builder(Dialect::Sqlite)
+        .build(&params(
+            vec![
+                (
+                    "athlete",
+                    FilterModelType::Combined(CombinedFilterModel::Text(CombinedTextFilter {
+                        conditions: vec![
+                            text(Comparator::StartsWith, "Mic"),
+                            text(Comparator::EndsWith, "PS"),
+                            text(Comparator::NotContains, "a_b"),
+                        ],
+                        operator: JoinOperator::And,
+                    })),
+                ),
+                (
+                    "age",
+                    FilterModelType::Combined(CombinedFilterModel::Number(CombinedNumberFilter {
+                        conditions: vec![
+                            number(Comparator::LessThan, 30.0),
+                            number(Comparator::LessThanOrEqual, 25.0),
+                            number(Comparator::NotEquals, 20.0),
+                        ],
+                        operator: JoinOperator::And,
+                    })),
+                ),
+            ],
+            vec![],
+        ))
+        .unwrap();
+
+    assert_eq!(
+        query.sql,
+        concat!(
+            r#"WHERE ("age" < ? AND "age" <= ? AND ("age" IS NULL OR "age" <> ?))"#,
+            r#" AND (LOWER("athlete") LIKE ? ESCAPE '\' AND LOWER("athlete") LIKE ? ESCAPE '\'"#,
+            r#" AND ("athlete" IS NULL OR LOWER("athlete") NOT LIKE ? ESCAPE '\'))"#,
+            r#" LIMIT 100 OFFSET 100"#,
+        )
+    );
+    assert_eq!(
+        query.values,
+        [
+            SqlValue::Number(30.0),
+            SqlValue::Number(25.0),
+            SqlValue::Number(20.0),
+            SqlValue::Text("mic%".to_string()),
+            SqlValue::Text("%ps".to_string()),
+            SqlValue::Text("%a\\_b%".to_string()),
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_unconstrained_conditions() {
+    let condition = |comparator| NumberFilter {
+        filter: Some(20.0),
+        filter_to: None,
+        comparator,
+    };
+    let combined = |operator| {
+        FilterModelType::Combined(CombinedFilterModel::Number(CombinedNumberFilter {
+            conditions: vec![
+                condition(Some(Comparator::GreaterThan)),
+                condition(Some(Comparator::ChooseOne)),
+            ],
+            operator,
+        }))
+    };
+
+    // The unconstrained condition is ignored by `AND`...
+    let query = builder(Dialect::Postgres)
+        .build(&params(vec![("age", combined(JoinOperator::And))], vec![]))
+        .unwrap();
+    assert_eq!(query.sql, r#"WHERE "age" > $1 LIMIT 100 OFFSET 100"#);
+    assert_eq!(query.values, [SqlValue::Number(20.0)]);
+
+    // ...but lets every row through `OR`.
+    let query = builder(Dialect::Postgres)
+        .build(&params(
+            vec![
+                ("age", combined(JoinOperator::Or)),
+                (
+                    "athlete",
+                    FilterModelType::Single(FilterModel::Text(text(Comparator::Equals, "Bolt"))),
+                ),
+            ],
+            vec![],
+        ))
+        .unwrap();
+    assert_eq!(
+        query.sql,
+        r#"WHERE LOWER("athlete") = $1 LIMIT 100 OFFSET 100"#
+    );
+    assert_eq!(query.values, [SqlValue::Text("bolt".to_string())]);
+}
+
+#[wasm_bindgen_test]
+fn test_blank_filters() {
+    let query = builder(Dialect::Sqlite)
+        .build(&params(
+            vec![
+                (
+                    "athlete",
+                    FilterModelType::Single(FilterModel::Text(TextFilter {
+                        filter: None,
+                        filter_to: None,
+                        comparator: Some(Comparator::Blank),
+                    })),
+                ),
+                (
+                    "age",
+                    FilterModelType::Single(FilterModel::Number(NumberFilter {
+                        filter: None,
+                        filter_to: None,
+                        comparator: Some(Comparator::NotBlank),
+                    })),
+                ),
+            ],
+            vec![],
+        ))
+        .unwrap();
+
+    assert_eq!(
+        query.sql,
+        r#"WHERE "age" IS NOT NULL AND ("athlete" IS NULL OR "athlete" = '') LIMIT 100 OFFSET 100"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_errors() {
+    let unknown =
+        builder(Dialect::Sqlite).build(&params(vec![], vec![("password", SortDirection::Asc)]));
+    assert_eq!(
+        unknown,
+        Err(QueryError::UnknownColumn("password".to_string()))
+    );
+
+    let custom = builder(Dialect::Sqlite).build(&params(
+        vec![("athlete", FilterModelType::Custom(JsValue::null()))],
+        vec![],
+    ));
+    assert_eq!(
+        custom,
+        Err(QueryError::UnsupportedFilter("athlete".to_string()))
+    );
+
+    let missing = builder(Dialect::Sqlite).build(&params(
+        vec![(
+            "age",
+            FilterModelType::Single(FilterModel::Number(NumberFilter {
+                filter: None,
+                filter_to: None,
+                comparator: Some(Comparator::Equals),
+            })),
+        )],
+        vec![],
+    ));
+    assert_eq!(missing, Err(QueryError::MissingValue("age".to_string())));
+}
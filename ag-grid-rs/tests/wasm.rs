@@ -1,8 +1,30 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
 use ag_grid_core::{convert::ToJsValue, imports::ObjectExt};
-use ag_grid_rs::{column::SortMethod, ColumnDef, GridOptions, ToJsValue};
-use js_sys::Array;
+use ag_grid_rs::{
+    callbacks::{
+        CellCallbackParams, CellEditorParams, CellRendererParams, ColSpanParams,
+        DoesFilterPassParams, FilterComponentParams, RowSpanParams, ValueFormatterParams,
+    },
+    column::{
+        ApplyColumnStateParams, CellEditorType, ColumnState, Filter, PinnedPosition,
+        SelectCellEditorParams, SortMethod,
+    },
+    components::{CellEditor, CellRenderer, FilterComponent},
+    events::{EventType, FilterChangedEvent},
+    filter::{
+        CombinedFilterModel, CombinedNumberFilter, Comparator, DateFilter, DateFilterParams,
+        FilterButton, FilterModel, FilterModelType, FilterParams, JoinOperator, MultiFilterDef,
+        MultiFilterDisplay, MultiFilterParams, NumberFilter, SetFilterParams, TextFilterParams,
+    },
+    ColumnDef, GridApi, GridOptions, ToJsValue,
+};
+use chrono::NaiveDateTime;
+use js_sys::{Array, Function, Object, Promise, JSON};
 use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
 
 #[wasm_bindgen_test]
 fn test_serialize_sort_method() {
@@ -11,6 +33,21 @@ fn test_serialize_sort_method() {
     assert!(SortMethod::Null.to_js_value().is_null());
 }
 
+#[wasm_bindgen_test]
+fn test_serialize_event_type() {
+    assert_eq!(
+        EventType::CellValueChanged
+            .to_js_value()
+            .as_string()
+            .unwrap(),
+        "cellValueChanged"
+    );
+    assert_eq!(
+        EventType::GridReady.to_js_value().as_string().unwrap(),
+        "gridReady"
+    );
+}
+
 #[wasm_bindgen_test]
 fn test_serialize_grid_options() {
     #[derive(ToJsValue)]
@@ -39,6 +76,187 @@ fn test_serialize_grid_options() {
     }
 }
 
+#[wasm_bindgen_test]
+fn test_from_js_value_round_trip() {
+    use ag_grid_rs::{convert::FromJsValue, FromJsValue};
+
+    #[derive(Debug, PartialEq, ToJsValue, FromJsValue)]
+    enum Medal {
+        Gold,
+        #[js_value(rename = "SILVER")]
+        Silver,
+        #[js_value(serialize_as = "null")]
+        None,
+    }
+
+    #[derive(Debug, PartialEq, ToJsValue, FromJsValue)]
+    struct Entry<T> {
+        athlete_name: String,
+        #[js_value(rename = "yearOfBirth")]
+        born: Option<u32>,
+        medals: Vec<Medal>,
+        scores: HashMap<String, f64>,
+        extra: T,
+    }
+
+    let entry = Entry {
+        athlete_name: "Michael Phelps".to_string(),
+        born: None,
+        medals: vec![Medal::Gold, Medal::Silver, Medal::None],
+        scores: HashMap::from([("heat".to_string(), 50.58)]),
+        extra: vec![true],
+    };
+
+    let js = entry.to_js_value();
+    assert_eq!(
+        JSON::stringify(&js).unwrap(),
+        r#"{"athleteName":"Michael Phelps","yearOfBirth":null,"medals":["gold","SILVER",null],"scores":{"heat":50.58},"extra":[true]}"#
+    );
+    assert_eq!(Entry::from_js_value(&js).unwrap(), entry);
+
+    let err = Entry::<Vec<bool>>::from_js_value(
+        &JSON::parse(
+            r#"{"athleteName": "Ian Thorpe", "medals": ["bronze"], "scores": {}, "extra": []}"#,
+        )
+        .unwrap(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to decode `medals`: expected one of `gold`, `SILVER`, null, found JsValue(\"bronze\")"
+    );
+    assert!(Medal::from_js_value(&JsValue::from_f64(1.0)).is_err());
+    assert!(Entry::<u32>::from_js_value(&JsValue::from_str("row")).is_err());
+
+    assert_eq!(<()>::from_js_value(&().to_js_value()).unwrap(), ());
+    assert!(<()>::from_js_value(&JsValue::null()).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_from_js_value_integers() {
+    use ag_grid_rs::convert::FromJsValue;
+
+    assert_eq!(u8::from_js_value(&JsValue::from_f64(255.0)).unwrap(), 255);
+    assert_eq!(i32::from_js_value(&JsValue::from_f64(-3.0)).unwrap(), -3);
+    assert_eq!(f64::from_js_value(&JsValue::from_f64(2.5)).unwrap(), 2.5);
+
+    assert_eq!(
+        u32::from_js_value(&JsValue::from_f64(2.5))
+            .unwrap_err()
+            .to_string(),
+        "expected an integer, found JsValue(2.5)"
+    );
+    assert_eq!(
+        u8::from_js_value(&JsValue::from_f64(256.0))
+            .unwrap_err()
+            .to_string(),
+        "256 is out of range for `u8`"
+    );
+    assert!(u32::from_js_value(&JsValue::from_f64(-1.0)).is_err());
+    assert!(u64::from_js_value(&JsValue::from_f64(2f64.powi(64))).is_err());
+    assert!(i64::from_js_value(&JsValue::from_f64(f64::NAN)).is_err());
+    assert!(usize::from_js_value(&JsValue::from_f64(f64::INFINITY)).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_event_handler_decodes_row_data() {
+    let received = Rc::new(RefCell::new(None));
+
+    let grid_options = GridOptions::<Vec<u32>>::new().on_cell_value_changed({
+        let received = received.clone();
+        move |e| *received.borrow_mut() = Some((e.row_index, e.data))
+    });
+
+    let handler = to_obj(&grid_options.to_js_value())
+        .get("onCellValueChanged")
+        .unchecked_into::<Function>();
+    let event = JSON::parse(r#"{"rowIndex": 3, "column": {}, "data": [1, 2]}"#).unwrap();
+    handler.call1(&JsValue::null(), &event).unwrap();
+
+    assert_eq!(*received.borrow(), Some((Some(3), Some(vec![1, 2]))));
+
+    // Row data which does not match `T` is logged rather than handled.
+    *received.borrow_mut() = None;
+    let event = JSON::parse(r#"{"rowIndex": 4, "column": {}, "data": ["a"]}"#).unwrap();
+    handler.call1(&JsValue::null(), &event).unwrap();
+
+    assert_eq!(*received.borrow(), None);
+}
+
+#[wasm_bindgen_test]
+fn test_event_handler_without_row_data() {
+    // Handlers which are not passed row data only need `T: ToJsValue`.
+    #[derive(ToJsValue)]
+    struct Row {
+        make: String,
+    }
+
+    let received = Rc::new(RefCell::new(None));
+    let grid_options = GridOptions::<Row>::new().on_sort_changed({
+        let received = received.clone();
+        move |e| *received.borrow_mut() = e.source
+    });
+
+    let handler = to_obj(&grid_options.to_js_value())
+        .get("onSortChanged")
+        .unchecked_into::<Function>();
+    let event = JSON::parse(r#"{"source": "uiColumnSorted"}"#).unwrap();
+    handler.call1(&JsValue::null(), &event).unwrap();
+
+    assert_eq!(received.borrow().as_deref(), Some("uiColumnSorted"));
+}
+
+#[wasm_bindgen_test]
+fn test_event_listener() {
+    // Stand in for the grid, which fires `filterChanged` whenever the filter
+    // model is set.
+    let api = Function::new_no_args(
+        "const listeners = [];
+        return {
+            addEventListener(type, listener) { listeners.push({ type, listener }); },
+            removeEventListener(type, listener) {
+                const i = listeners.findIndex((l) => l.type === type && l.listener === listener);
+                if (i !== -1) listeners.splice(i, 1);
+            },
+            setFilterModel() {
+                const columns = [{ getColId: () => 'price' }];
+                listeners
+                    .filter((l) => l.type === 'filterChanged')
+                    .forEach((l) => l.listener({ columns }));
+            },
+        };",
+    )
+    .call0(&JsValue::null())
+    .unwrap()
+    .unchecked_into::<GridApi>();
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let listener = api.add_event_listener({
+        let received = received.clone();
+        move |e: FilterChangedEvent| {
+            received
+                .borrow_mut()
+                .extend(e.columns.iter().map(|column| column.col_id()))
+        }
+    });
+
+    api.set_filter_model(HashMap::new());
+    assert_eq!(*received.borrow(), ["price"]);
+
+    api.remove_event_listener(listener);
+    api.set_filter_model(HashMap::new());
+    assert_eq!(*received.borrow(), ["price"]);
+
+    // Dropping the guard removes the listener too.
+    let listener = api.add_event_listener({
+        let received = received.clone();
+        move |_: FilterChangedEvent| received.borrow_mut().push("dropped".to_string())
+    });
+    drop(listener);
+    api.set_filter_model(HashMap::new());
+    assert_eq!(*received.borrow(), ["price"]);
+}
+
 #[wasm_bindgen_test]
 fn test_serialize_column() {
     let col = ColumnDef::new().field("make").to_js_value();
@@ -46,6 +264,701 @@ fn test_serialize_column() {
     assert_eq!(to_obj(&col).get("field").as_string().unwrap(), "make");
 }
 
+#[wasm_bindgen_test]
+fn test_skip_serializing_none() {
+    #[derive(ToJsValue)]
+    struct Data {
+        make: Option<String>,
+    }
+
+    #[derive(ToJsValue)]
+    #[js_value(skip_serializing_none)]
+    struct SkippedData {
+        make: Option<String>,
+    }
+
+    // By default, `None` is serialized to `null`...
+    let obj = to_obj(&Data { make: None }.to_js_value());
+    assert!(obj.keys().contains(&"make".to_string()));
+    assert!(obj.get("make").is_null());
+
+    // ...unless the container opts out.
+    let obj = to_obj(&SkippedData { make: None }.to_js_value());
+    assert!(!obj.keys().contains(&"make".to_string()));
+
+    // `ColumnDef` and `GridOptions` omit their unset options entirely.
+    let col = to_obj(&ColumnDef::new().field("make").to_js_value());
+    assert_eq!(col.keys(), vec!["field".to_string()]);
+
+    let grid_options = to_obj(&GridOptions::<Data>::new().pagination(true).to_js_value());
+    assert!(grid_options.get("pagination").as_bool().unwrap());
+    assert!(!grid_options.keys().contains(&"rowData".to_string()));
+    assert!(!grid_options.keys().contains(&"columnDefs".to_string()));
+}
+
+#[wasm_bindgen_test]
+fn test_serialize_column_state() {
+    let state = ColumnState {
+        col_id: "make".to_string(),
+        pinned: Some(PinnedPosition::False),
+        sort: Some(SortMethod::Null),
+        sort_index: Some(None),
+        width: Some(120),
+        ..Default::default()
+    };
+    let params = ApplyColumnStateParams::new(vec![state])
+        .apply_order(true)
+        .to_js_value();
+
+    let obj = to_obj(&params);
+    assert!(obj.get("applyOrder").as_bool().unwrap());
+    assert!(!obj.keys().contains(&"defaultState".to_string()));
+
+    let state = obj.get("state").unchecked_into::<Array>().get(0);
+    let state = to_obj(&state);
+    assert_eq!(state.get("colId").as_string().unwrap(), "make");
+    assert_eq!(state.get("width").as_f64().unwrap(), 120f64);
+    assert!(!state.get("pinned").as_bool().unwrap());
+    assert!(state.get("sort").is_null());
+    assert!(state.get("sortIndex").is_null());
+    assert!(!state.keys().contains(&"hide".to_string()));
+    assert!(!state.keys().contains(&"flex".to_string()));
+}
+
+#[cfg(feature = "serde")]
+#[wasm_bindgen_test]
+fn test_serde_round_trip() {
+    use std::collections::HashMap;
+
+    use ag_grid_rs::sort::SortModelItem;
+    use serde_json::{json, Value};
+
+    fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: Value) {
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(parsed).unwrap(), value);
+    }
+
+    round_trip::<HashMap<String, FilterModelType>>(json!({
+        "athlete": {"filterType": "text", "type": "notEqual", "filter": "Phelps"},
+        "age": {
+            "filterType": "number",
+            "operator": "OR",
+            "conditions": [
+                {"type": "lessThan", "filter": 20.0},
+                {"type": "inRange", "filter": 30.0, "filterTo": 40.0},
+                {"type": "equals", "filter": 50.0},
+            ],
+            "condition1": {"type": "lessThan", "filter": 20.0},
+            "condition2": {"type": "inRange", "filter": 30.0, "filterTo": 40.0},
+        },
+        "date": {"filterType": "date", "type": "equals", "dateFrom": "2008-08-24 00:00:00"},
+        "country": {"filterType": "set", "values": ["Norway", null]},
+        "sport": {
+            "filterType": "multi",
+            "filterModels": [null, {"filterType": "set", "values": ["Swimming"]}],
+        },
+    }));
+
+    // Models from AG Grid versions before 29 only have two conditions.
+    let legacy: FilterModelType = serde_json::from_value(json!({
+        "filterType": "text",
+        "operator": "AND",
+        "condition1": {"type": "contains", "filter": "a"},
+        "condition2": {"type": "contains", "filter": "b"},
+    }))
+    .unwrap();
+    match legacy {
+        FilterModelType::Combined(CombinedFilterModel::Text(filter)) => {
+            assert_eq!(filter.conditions.len(), 2)
+        }
+        _ => panic!("expected a combined text filter"),
+    }
+
+    round_trip::<Vec<SortModelItem>>(json!([{"colId": "athlete", "sort": "desc"}]));
+    round_trip::<Vec<ColumnState>>(json!([
+        {"colId": "athlete", "width": 200, "hide": false, "pinned": "left", "sort": "asc", "sortIndex": 0},
+        {"colId": "age", "pinned": false, "sort": null, "sortIndex": null},
+    ]));
+}
+
+#[wasm_bindgen_test]
+fn test_value_getter_and_formatter() {
+    let col_def = ColumnDef::new()
+        .value_getter("data.price * 2")
+        .value_formatter_fn(|params: ValueFormatterParams<JsValue>| {
+            format!("£{}", params.value.as_f64().unwrap())
+        });
+    let obj = to_obj(&col_def.to_js_value());
+
+    assert_eq!(
+        obj.get("valueGetter").as_string().unwrap(),
+        "data.price * 2"
+    );
+
+    let formatter = obj.get("valueFormatter").unchecked_into::<Function>();
+    let params = callback_params("price", "null");
+    js_sys::Reflect::set(&params, &"value".into(), &42.into()).unwrap();
+
+    let formatted = formatter.call1(&JsValue::null(), &params).unwrap();
+    assert_eq!(formatted.as_string().unwrap(), "£42");
+
+    // The closure is owned by the column definition, and so freed with it.
+    drop(col_def);
+    assert!(formatter.call1(&JsValue::null(), &params).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_bool_or_callback() {
+    let col_def = ColumnDef::new()
+        .editable(true)
+        .checkbox_selection(false)
+        .row_drag_fn(|params: CellCallbackParams<Vec<u32>>| {
+            params.data.unwrap().contains(&1) && params.col_id == "price"
+        });
+    let obj = to_obj(&col_def.to_js_value());
+
+    assert!(obj.get("editable").as_bool().unwrap());
+    assert!(!obj.get("checkboxSelection").as_bool().unwrap());
+
+    let row_drag = obj.get("rowDrag").unchecked_into::<Function>();
+    let params = callback_params("price", "[1, 2]");
+    assert!(row_drag
+        .call1(&JsValue::null(), &params)
+        .unwrap()
+        .as_bool()
+        .unwrap());
+
+    let col_def = ColumnDef::new().checkbox_selection_fn(|params: CellCallbackParams<Vec<u32>>| {
+        params.data.is_some_and(|data| data.len() > 2)
+    });
+    let checkbox_selection = to_obj(&col_def.to_js_value())
+        .get("checkboxSelection")
+        .unchecked_into::<Function>();
+
+    let params = callback_params("price", "[1, 2]");
+    assert_eq!(
+        checkbox_selection.call1(&JsValue::null(), &params).unwrap(),
+        JsValue::FALSE
+    );
+    let params = callback_params("price", "[1, 2, 3]");
+    assert_eq!(
+        checkbox_selection.call1(&JsValue::null(), &params).unwrap(),
+        JsValue::TRUE
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_span_callbacks() {
+    let col_def = ColumnDef::new()
+        .col_span_fn(|params: ColSpanParams<Vec<u32>>| {
+            if params.column.col_id() == "country" {
+                params.data.unwrap().len() as u32
+            } else {
+                1
+            }
+        })
+        .row_span_fn(|params: RowSpanParams<Vec<u32>>| params.row_index.unwrap() + 1);
+    let obj = to_obj(&col_def.to_js_value());
+
+    let params = callback_params("country", "[1, 2, 3]");
+    let node = JSON::parse(r#"{"rowIndex": 4}"#).unwrap();
+    js_sys::Reflect::set(&params, &"node".into(), &node).unwrap();
+
+    let col_span = obj.get("colSpan").unchecked_into::<Function>();
+    assert_eq!(
+        col_span
+            .call1(&JsValue::null(), &params)
+            .unwrap()
+            .as_f64()
+            .unwrap(),
+        3f64
+    );
+
+    let row_span = obj.get("rowSpan").unchecked_into::<Function>();
+    assert_eq!(
+        row_span
+            .call1(&JsValue::null(), &params)
+            .unwrap()
+            .as_f64()
+            .unwrap(),
+        5f64
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_cell_renderer() {
+    #[derive(Default)]
+    struct Renderer;
+
+    impl CellRenderer for Renderer {
+        type Row = Vec<u32>;
+
+        fn init(&mut self, params: CellRendererParams<Vec<u32>>) -> HtmlElement {
+            assert_eq!(params.data.unwrap(), [1, 2]);
+            assert_eq!(params.row_index, Some(3));
+            // There is no DOM under Node, so stand in a plain object for the element.
+            js_sys::Object::new().unchecked_into()
+        }
+
+        fn refresh(&mut self, params: CellRendererParams<Vec<u32>>) -> bool {
+            params.value.as_f64() == Some(42.0)
+        }
+    }
+
+    // The column definition owns the component class, so must be kept alive.
+    let col_def = ColumnDef::new().cell_renderer(Renderer::default);
+    let col = col_def.to_js_value();
+    let class = to_obj(&col)
+        .get("cellRenderer")
+        .unchecked_into::<Function>();
+
+    let params = callback_params("price", "[1, 2]");
+    js_sys::Reflect::set(&params, &"value".into(), &42.into()).unwrap();
+    let node = JSON::parse(r#"{"rowIndex": 3}"#).unwrap();
+    js_sys::Reflect::set(&params, &"node".into(), &node).unwrap();
+
+    let instance = to_obj(&js_sys::Reflect::construct(&class, &Array::new()).unwrap());
+    let call = |method: &str, arg: &JsValue| {
+        instance
+            .get(method)
+            .unchecked_into::<Function>()
+            .call1(&instance, arg)
+            .unwrap()
+    };
+
+    call("init", &params);
+    let gui = call("getGui", &JsValue::undefined());
+    assert!(gui.is_object());
+    assert!(call("refresh", &params).as_bool().unwrap());
+    call("destroy", &JsValue::undefined());
+
+    // The instance's methods are freed once it is destroyed.
+    assert!(instance
+        .get("getGui")
+        .unchecked_into::<Function>()
+        .call0(&instance)
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_cell_editor() {
+    let col = ColumnDef::new()
+        .cell_editor(CellEditorType::AgSelectCellEditor(
+            SelectCellEditorParams::new(&["Ford", "Toyota"]),
+        ))
+        .to_js_value();
+    assert_eq!(
+        JSON::stringify(&col).unwrap(),
+        r#"{"cellEditor":"agSelectCellEditor","cellEditorParams":{"values":["Ford","Toyota"]}}"#
+    );
+
+    #[derive(Default)]
+    struct Editor {
+        value: u32,
+    }
+
+    impl CellEditor for Editor {
+        type Row = JsValue;
+        type Value = u32;
+
+        fn init(&mut self, params: CellEditorParams<JsValue>) {
+            self.value = params.value.as_f64().unwrap() as u32;
+        }
+
+        fn get_gui(&self) -> HtmlElement {
+            js_sys::Object::new().unchecked_into()
+        }
+
+        fn get_value(&self) -> u32 {
+            self.value + 1
+        }
+
+        fn is_popup(&self) -> bool {
+            true
+        }
+    }
+
+    let col_def = ColumnDef::new().cell_editor(CellEditorType::custom(Editor::default));
+    let col = col_def.to_js_value();
+    let class = to_obj(&col).get("cellEditor").unchecked_into::<Function>();
+    let instance = to_obj(&js_sys::Reflect::construct(&class, &Array::new()).unwrap());
+    let call = |method: &str, arg: &JsValue| {
+        instance
+            .get(method)
+            .unchecked_into::<Function>()
+            .call1(&instance, arg)
+            .unwrap()
+    };
+
+    let params = callback_params("price", "null");
+    js_sys::Reflect::set(&params, &"value".into(), &41.into()).unwrap();
+
+    call("init", &params);
+    assert!(call("isPopup", &JsValue::undefined()).as_bool().unwrap());
+    assert!(!call("isCancelAfterEnd", &JsValue::undefined())
+        .as_bool()
+        .unwrap());
+    assert_eq!(call("getValue", &JsValue::undefined()).as_f64(), Some(42.0));
+    call("destroy", &JsValue::undefined());
+    assert!(instance
+        .get("getValue")
+        .unchecked_into::<Function>()
+        .call0(&instance)
+        .is_err());
+
+    // A custom editor has no parameters to pass.
+    assert!(!to_obj(&col)
+        .keys()
+        .contains(&"cellEditorParams".to_string()));
+}
+
+#[wasm_bindgen_test]
+fn test_filter_component() {
+    #[derive(Default)]
+    struct TagFilter {
+        tag: Option<String>,
+    }
+
+    impl FilterComponent for TagFilter {
+        type Row = Vec<String>;
+        type Model = String;
+
+        fn init(&mut self, _params: FilterComponentParams) {}
+
+        fn get_gui(&self) -> HtmlElement {
+            js_sys::Object::new().unchecked_into()
+        }
+
+        fn does_filter_pass(&self, params: DoesFilterPassParams<Vec<String>>) -> bool {
+            self.tag
+                .as_ref()
+                .is_none_or(|tag| params.data.contains(tag))
+        }
+
+        fn is_filter_active(&self) -> bool {
+            self.tag.is_some()
+        }
+
+        fn get_model(&self) -> Option<String> {
+            self.tag.clone()
+        }
+
+        fn set_model(&mut self, model: Option<String>) {
+            self.tag = model;
+        }
+    }
+
+    let col = ColumnDef::new().filter(Filter::True).to_js_value();
+    assert!(to_obj(&col).get("filter").as_bool().unwrap());
+
+    let col_def = ColumnDef::new().filter(Filter::custom(TagFilter::default));
+    let col = col_def.to_js_value();
+    let class = to_obj(&col).get("filter").unchecked_into::<Function>();
+    let instance = to_obj(&js_sys::Reflect::construct(&class, &Array::new()).unwrap());
+    let call = |method: &str, arg: &JsValue| {
+        instance
+            .get(method)
+            .unchecked_into::<Function>()
+            .call1(&instance, arg)
+            .unwrap()
+    };
+
+    assert!(call("getModel", &JsValue::undefined()).is_null());
+    call("setModel", &"red".into());
+    assert!(call("isFilterActive", &JsValue::undefined())
+        .as_bool()
+        .unwrap());
+    assert_eq!(
+        call("getModel", &JsValue::undefined()).as_string().unwrap(),
+        "red"
+    );
+
+    let params = JSON::parse(r#"{"data": ["blue", "red"]}"#).unwrap();
+    assert!(call("doesFilterPass", &params).as_bool().unwrap());
+
+    // A model which cannot be decoded is logged and ignored.
+    call("setModel", &42.into());
+    assert_eq!(
+        call("getModel", &JsValue::undefined()).as_string().unwrap(),
+        "red"
+    );
+
+    call("setModel", &JsValue::null());
+    assert!(!call("isFilterActive", &JsValue::undefined())
+        .as_bool()
+        .unwrap());
+
+    call("destroy", &JsValue::undefined());
+    assert!(instance
+        .get("getModel")
+        .unchecked_into::<Function>()
+        .call0(&instance)
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_filter_params() {
+    let col = ColumnDef::new()
+        .filter(Filter::AgTextColumnFilter)
+        .filter_params(FilterParams::Text(
+            TextFilterParams::new()
+                .filter_options(vec![Comparator::Contains, Comparator::NotEquals])
+                .default_option(Comparator::NotEquals)
+                .buttons(vec![FilterButton::Apply, FilterButton::Reset])
+                .case_sensitive(true),
+        ))
+        .to_js_value();
+    assert_eq!(
+        JSON::stringify(&col).unwrap(),
+        r#"{"filter":"agTextColumnFilter","filterParams":{"filterOptions":["contains","notEqual"],"defaultOption":"notEqual","buttons":["apply","reset"],"caseSensitive":true}}"#
+    );
+
+    let params = DateFilterParams::new().comparator(|filter_date, cell_value| {
+        let cell_date =
+            NaiveDateTime::parse_from_str(&cell_value.as_string().unwrap(), "%Y-%m-%d %H:%M")
+                .unwrap();
+        cell_date.cmp(&filter_date)
+    });
+    let comparator = to_obj(&params.to_js_value())
+        .get("comparator")
+        .unchecked_into::<Function>();
+    let filter_date = js_sys::Date::new_with_year_month_day(2023, 0, 15);
+
+    let compare = |cell_value: &str| {
+        comparator
+            .call2(&JsValue::null(), &filter_date, &cell_value.into())
+            .unwrap()
+            .as_f64()
+            .unwrap()
+    };
+    assert_eq!(compare("2023-01-14 12:00"), -1.0);
+    assert_eq!(compare("2023-01-15 00:00"), 0.0);
+    assert_eq!(compare("2023-01-16 00:00"), 1.0);
+
+    // An invalid filter date matches no cell.
+    let invalid_date = js_sys::Date::new(&JsValue::from_str("not a date"));
+    assert!(comparator
+        .call2(&JsValue::null(), &invalid_date, &"2023-01-15 00:00".into())
+        .unwrap()
+        .as_f64()
+        .unwrap()
+        .is_nan());
+
+    // The closure is owned by the params, and so freed with them.
+    drop(params);
+    assert!(comparator
+        .call2(&JsValue::null(), &filter_date, &"2023-01-15 00:00".into())
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_serialize_filter_model() {
+    let model = FilterModelType::Combined(CombinedFilterModel::Number(CombinedNumberFilter {
+        conditions: vec![
+            NumberFilter {
+                filter: Some(1.0),
+                filter_to: Some(5.0),
+                comparator: Some(Comparator::InRange),
+            },
+            NumberFilter {
+                filter: None,
+                filter_to: None,
+                comparator: Some(Comparator::Blank),
+            },
+            NumberFilter {
+                filter: Some(9.0),
+                filter_to: None,
+                comparator: Some(Comparator::Equals),
+            },
+        ],
+        operator: JoinOperator::Or,
+    }));
+    assert_eq!(
+        JSON::stringify(&model.to_js_value()).unwrap(),
+        r#"{"conditions":[{"filter":1,"filterTo":5,"type":"inRange"},{"type":"blank"},{"filter":9,"type":"equals"}],"condition1":{"filter":1,"filterTo":5,"type":"inRange"},"condition2":{"type":"blank"},"operator":"OR","filterType":"number"}"#
+    );
+
+    let model = FilterModelType::Single(FilterModel::Date(DateFilter {
+        filter: NaiveDateTime::parse_from_str("2023-01-15 00:00:00", "%Y-%m-%d %H:%M:%S").ok(),
+        filter_to: None,
+        comparator: Some(Comparator::NotEquals),
+    }));
+    assert_eq!(
+        JSON::stringify(&model.to_js_value()).unwrap(),
+        r#"{"dateFrom":"2023-01-15 00:00:00","type":"notEqual","filterType":"date"}"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_parse_combined_filter_model() {
+    let conditions = |model: &str| {
+        let obj = JSON::parse(model).unwrap().unchecked_into::<ObjectExt>();
+        match CombinedFilterModel::try_from(&obj).unwrap() {
+            CombinedFilterModel::Text(filter) => filter
+                .conditions
+                .into_iter()
+                .map(|c| c.filter.unwrap())
+                .collect::<Vec<_>>(),
+            _ => panic!("expected a combined text filter"),
+        }
+    };
+
+    assert_eq!(
+        conditions(
+            r#"{"filterType": "text", "operator": "OR", "conditions": [
+                {"type": "contains", "filter": "a"},
+                {"type": "contains", "filter": "b"},
+                {"type": "contains", "filter": "c"}
+            ]}"#
+        ),
+        ["a", "b", "c"]
+    );
+    assert_eq!(
+        conditions(
+            r#"{"filterType": "text", "operator": "AND",
+                "condition1": {"type": "contains", "filter": "a"},
+                "condition2": {"type": "contains", "filter": "b"}
+            }"#
+        ),
+        ["a", "b"]
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_parse_filter_model_errors() {
+    let parse = |model: &str| {
+        let obj = JSON::parse(model).unwrap().unchecked_into::<ObjectExt>();
+        FilterModel::try_from(&obj)
+    };
+
+    let date = parse(
+        r#"{"filterType": "date", "type": "inRange",
+            "dateFrom": "2023-01-01 00:00:00", "dateTo": "2023-02-01 00:00:00"}"#,
+    )
+    .unwrap();
+    match date {
+        FilterModel::Date(filter) => {
+            assert_eq!(filter.filter_to.unwrap().to_string(), "2023-02-01 00:00:00")
+        }
+        _ => panic!("expected a date filter"),
+    }
+
+    assert!(parse(r#"{"filterType": "text", "type": "fuzzy"}"#).is_err());
+    assert!(parse(r#"{"filterType": "colour"}"#).is_err());
+    assert!(parse(r#"{"filterType": "date", "dateFrom": "yesterday"}"#).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_set_filter() {
+    let params = FilterParams::Set(
+        SetFilterParams::new()
+            .values(vec![Some("Ford".to_string()), None])
+            .suppress_select_all(true),
+    )
+    .to_js_value();
+    assert_eq!(
+        JSON::stringify(&params).unwrap(),
+        r#"{"values":["Ford",null],"suppressSelectAll":true}"#
+    );
+
+    let obj = JSON::parse(r#"{"filterType": "set", "values": ["Ford", null]}"#)
+        .unwrap()
+        .unchecked_into::<ObjectExt>();
+    let model = FilterModel::try_from(&obj).unwrap();
+    match &model {
+        FilterModel::Set(filter) => assert_eq!(filter.values, [Some("Ford".to_string()), None]),
+        _ => panic!("expected a set filter"),
+    }
+    assert_eq!(
+        JSON::stringify(&model.to_js_value()).unwrap(),
+        r#"{"values":["Ford",null],"filterType":"set"}"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_set_filter_values_fn() {
+    let params = SetFilterParams::new()
+        .values_fn(|params| async move { vec![Some(params.column.col_id()), None] });
+    let values = to_obj(&params.to_js_value())
+        .get("values")
+        .unchecked_into::<Function>();
+
+    // The grid passes a callback, which is called once the values are loaded.
+    let loaded = Promise::new(&mut |resolve, _reject| {
+        let column = Object::new().unchecked_into::<ObjectExt>();
+        column.set("getColId", Function::new_no_args("return 'make';").into());
+        let js_params = Object::new().unchecked_into::<ObjectExt>();
+        js_params.set("column", column.into());
+        js_params.set("success", resolve.into());
+        values.call1(&JsValue::null(), &js_params).unwrap();
+    });
+    let loaded = JsFuture::from(loaded).await.unwrap();
+    assert_eq!(JSON::stringify(&loaded).unwrap(), r#"["make",null]"#);
+
+    // The closure is owned by the params, and so freed with them.
+    drop(params);
+    assert!(values.call1(&JsValue::null(), &Object::new()).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_multi_filter() {
+    let params = FilterParams::Multi(MultiFilterParams::new(vec![
+        MultiFilterDef::new(Filter::AgTextColumnFilter),
+        MultiFilterDef::new(Filter::AgSetColumnFilter)
+            .display(MultiFilterDisplay::SubMenu)
+            .filter_params(FilterParams::Set(
+                SetFilterParams::new().suppress_mini_filter(true),
+            )),
+    ]))
+    .to_js_value();
+    assert_eq!(
+        JSON::stringify(&params).unwrap(),
+        r#"{"filters":[{"filter":"agTextColumnFilter"},{"filter":"agSetColumnFilter","filterParams":{"suppressMiniFilter":true},"display":"subMenu"}]}"#
+    );
+
+    let model = JSON::parse(
+        r#"{"filterType": "multi", "filterModels": [
+            {"filterType": "text", "operator": "OR", "conditions": [
+                {"type": "contains", "filter": "a"},
+                {"type": "contains", "filter": "b"}
+            ]},
+            null
+        ]}"#,
+    )
+    .unwrap();
+    let model = FilterModelType::try_from(model).unwrap();
+    match &model {
+        FilterModelType::Single(FilterModel::Multi(filter)) => {
+            assert!(matches!(
+                filter.filter_models[0],
+                Some(FilterModelType::Combined(CombinedFilterModel::Text(_)))
+            ));
+            assert!(filter.filter_models[1].is_none());
+        }
+        _ => panic!("expected a multi filter"),
+    }
+
+    // An inactive child filter may also be `undefined`.
+    let model = JSON::parse(r#"{"filterType": "multi"}"#).unwrap();
+    let filter_models = Array::of2(&JsValue::undefined(), &JsValue::null());
+    js_sys::Reflect::set(&model, &"filterModels".into(), &filter_models).unwrap();
+    match FilterModelType::try_from(model).unwrap() {
+        FilterModelType::Single(FilterModel::Multi(filter)) => {
+            assert!(filter.filter_models.iter().all(Option::is_none));
+        }
+        _ => panic!("expected a multi filter"),
+    }
+}
+
+/// Build a parameters object as passed by AG Grid to column callbacks.
+fn callback_params(col_id: &str, data: &str) -> JsValue {
+    let params = JSON::parse(&format!(r#"{{"data": {data}, "column": {{}}}}"#)).unwrap();
+    let column = js_sys::Reflect::get(&params, &"column".into()).unwrap();
+    let get_col_id = Function::new_no_args(&format!("return '{col_id}';"));
+    js_sys::Reflect::set(&column, &"getColId".into(), &get_col_id).unwrap();
+    params
+}
+
 fn to_obj(value: &JsValue) -> ObjectExt {
     value.to_owned().unchecked_into::<ObjectExt>()
 }
@@ -1,7 +1,6 @@
 use ag_grid_rs::{
     gridoptions::{DataSourceBuilder, RowModelType},
-    sort::SortDirection,
-    ColumnDef, GridOptions, ToJsValue,
+    ColumnDef, ColumnValues, GridOptions, ToJsValue,
 };
 use gloo_net::http::Request;
 use serde::Deserialize;
@@ -17,7 +16,7 @@ pub fn app() -> Html {
             // Get the element to which you want to attach the grid
             let grid_div = get_element_by_id("grid-div");
             // Define your columns
-            let field_names = vec!["athlete", "age", "country", "year"];
+            let field_names = ["athlete", "age", "country", "year"];
             let cols = field_names
                 .iter()
                 .map(|name| ColumnDef::new().field(name).sortable(true))
@@ -38,46 +37,21 @@ pub fn app() -> Html {
 
                 // Typically, one would send this information to the backend to perform the
                 // sorting/filtering/range selection, rather than doing it all manually here
-                if let Some(sort_model) = params.sort_model.get(0) {
-                    match (sort_model.col_id.as_str(), sort_model.sort) {
-                        ("athlete", SortDirection::Asc) => {
-                            rows.sort_by(|a, b| b.athlete.cmp(&a.athlete))
-                        }
-                        ("athlete", SortDirection::Desc) => {
-                            rows.sort_by(|a, b| a.athlete.cmp(&b.athlete))
-                        }
-                        ("age", SortDirection::Asc) => rows.sort_by(|a, b| b.age.cmp(&a.age)),
-                        ("age", SortDirection::Desc) => rows.sort_by(|a, b| a.age.cmp(&b.age)),
-                        ("country", SortDirection::Asc) => {
-                            rows.sort_by(|a, b| b.country.cmp(&a.country))
-                        }
-                        ("country", SortDirection::Desc) => {
-                            rows.sort_by(|a, b| a.country.cmp(&b.country))
-                        }
-                        ("year", SortDirection::Asc) => rows.sort_by(|a, b| b.year.cmp(&a.year)),
-                        ("year", SortDirection::Desc) => rows.sort_by(|a, b| a.year.cmp(&b.year)),
-                        _ => unreachable!(),
-                    }
-                }
+                let last_row = params.apply(&mut rows);
 
-                let rows = rows
-                    .into_iter()
-                    .skip(params.start_row as usize)
-                    .take(params.end_row as usize)
-                    .collect();
-
-                Ok((rows, None))
+                Ok((rows, Some(last_row as u32)))
             })
             .build();
 
-            let _grid = GridOptions::<JsonData>::new()
+            let grid = GridOptions::<JsonData>::new()
                 .column_defs(cols)
                 .row_model_type(RowModelType::Infinite)
                 .datasource(data_source)
                 .build(grid_div);
 
-            // `_grid` now provides a handle to the grid and column APIs
-            || ()
+            // `grid` now provides a handle to the grid and column APIs. It owns the
+            // column definitions, so keep it until the component is unmounted
+            move || drop(grid)
         },
         (),
     );
@@ -89,7 +63,7 @@ pub fn app() -> Html {
     }
 }
 
-#[derive(ToJsValue, Deserialize, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(ColumnValues, ToJsValue, Deserialize)]
 struct JsonData {
     athlete: String,
     age: Option<usize>,